@@ -248,7 +248,7 @@ mod tests {
     fn bench_rewrite_separator_medium() {
         let csv_text = include_str!("../test_files/medium.csv");
         let start = Instant::now();
-        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/");
+        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/").unwrap();
         let elapsed = start.elapsed();
         println!(
             "📊 rewrite separator (medium.csv, {} bytes) -> {:?}",
@@ -261,7 +261,7 @@ mod tests {
     fn bench_rewrite_separator_large() {
         let csv_text = include_str!("../test_files/large.csv");
         let start = Instant::now();
-        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/");
+        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/").unwrap();
         let elapsed = start.elapsed();
         println!(
             "📊 rewrite separator (large.csv, {} bytes) -> {:?}",
@@ -274,7 +274,7 @@ mod tests {
     fn bench_rewrite_separator_xlarge() {
         let csv_text = include_str!("../test_files/xlarge.csv");
         let start = Instant::now();
-        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/");
+        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/").unwrap();
         let elapsed = start.elapsed();
         println!(
             "📊 rewrite separator (xlarge.csv, {} bytes) -> {:?}",
@@ -287,7 +287,7 @@ mod tests {
     fn bench_rewrite_separator_xxlarge() {
         let csv_text = include_str!("../test_files/xxlarge.csv");
         let start = Instant::now();
-        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/");
+        let _out = crate::transform::rewrite_key_separator_in_csv(csv_text, "/").unwrap();
         let elapsed = start.elapsed();
         println!(
             "📊 rewrite separator (xxlarge.csv, {} bytes) -> {:?}",
@@ -0,0 +1,346 @@
+//! 두 버전의 번역 데이터 비교
+//!
+//! CSV, Excel, JSON(`LangJsonInput` 배열) 중 무엇이든 언어별 flat 키-값으로 바꾼 뒤
+//! 추가/삭제된 키와 언어별로 바뀐 값을 찾습니다.
+
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::export::{flatten_locale, merge_jsons_to_table, value_to_cell, LangJsonInput, TableData};
+use crate::parser;
+use crate::types::{ParseOptions, ParseResult};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// 비교할 입력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffFormat {
+    Csv,
+    Excel,
+    /// `[{ "language": "en", "content": "{...}" }, ...]`
+    Json,
+}
+
+impl DiffFormat {
+    /// 이름에서 변환 ("csv", "excel"/"xlsx", "json"), 빈 문자열이면 데이터로 판단
+    pub fn resolve(name: &str, data: &[u8]) -> Result<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "" => Ok(Self::detect(data)),
+            "csv" => Ok(Self::Csv),
+            "excel" | "xlsx" | "xls" => Ok(Self::Excel),
+            "json" => Ok(Self::Json),
+            other => Err(ParseError::new(
+                ErrorKind::Unknown,
+                format!("Unknown input format '{}'", other),
+            )
+            .with_suggestion("Use 'csv', 'excel', 'json' or an empty string to detect it")),
+        }
+    }
+
+    /// 데이터 내용으로 형식 판단 (zip/ole 시그니처 -> Excel, `[` -> JSON, 그 외 CSV)
+    pub fn detect(data: &[u8]) -> Self {
+        if data.starts_with(b"PK\x03\x04") || data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]) {
+            Self::Excel
+        } else if data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'[') {
+            Self::Json
+        } else {
+            Self::Csv
+        }
+    }
+}
+
+/// 비교용 번역 데이터: 언어 순서 + 언어별 (flat 키 -> 값), 빈 값은 없는 것으로 취급
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocaleSnapshot {
+    pub languages: Vec<String>,
+    pub values: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl LocaleSnapshot {
+    /// 파싱 결과에서 생성 (nested 키는 `separator`로 이어 붙임)
+    pub fn from_result(result: &ParseResult, separator: &str) -> Self {
+        let values = result
+            .languages
+            .iter()
+            .map(|lang| {
+                let flat = result
+                    .data
+                    .get(lang)
+                    .map(|data| flatten_locale(data, separator))
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), value_to_cell(v)))
+                    .filter(|(_, v)| !v.is_empty())
+                    .collect();
+                (lang.clone(), flat)
+            })
+            .collect();
+
+        Self {
+            languages: result.languages.clone(),
+            values,
+        }
+    }
+
+    /// `merge_jsons_to_table` 결과 같은 표에서 생성 (첫 컬럼이 key)
+    pub fn from_table(table: &TableData) -> Self {
+        let languages: Vec<String> = table.header.iter().skip(1).cloned().collect();
+        let mut values: BTreeMap<String, BTreeMap<String, String>> =
+            languages.iter().map(|lang| (lang.clone(), BTreeMap::new())).collect();
+
+        for row in &table.rows {
+            let Some(key) = row.first() else {
+                continue;
+            };
+            for (lang, value) in languages.iter().zip(row.iter().skip(1)) {
+                if !value.is_empty() {
+                    values.get_mut(lang).unwrap().insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Self { languages, values }
+    }
+
+    /// CSV/Excel/JSON 데이터에서 생성
+    ///
+    /// CSV/Excel은 escape 변환 없이 flat 키 그대로 읽고, Excel은 첫 번째 시트를 사용합니다.
+    pub fn load(data: &[u8], format: DiffFormat, separator: &str) -> Result<Self> {
+        let options = ParseOptions {
+            separator: separator.to_string(),
+            nested: false,
+            process_escapes: false,
+            ..Default::default()
+        };
+
+        match format {
+            DiffFormat::Csv => Ok(Self::from_result(&parser::csv::parse(data, &options)?, separator)),
+            DiffFormat::Excel => Ok(Self::from_result(&parser::excel::parse(data, &options)?, separator)),
+            DiffFormat::Json => {
+                let inputs: Vec<LangJsonInput> =
+                    serde_json::from_slice(data).map_err(|e| ParseError::json_parse_error("inputs", e))?;
+                Ok(Self::from_table(&merge_jsons_to_table(&inputs, separator)?))
+            }
+        }
+    }
+
+    fn keys(&self) -> BTreeSet<&String> {
+        self.values.values().flat_map(|m| m.keys()).collect()
+    }
+}
+
+/// 값 하나의 변경 (추가: `old` 없음, 삭제: `new` 없음)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValueChange {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+/// 한 언어의 변경 내역
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LanguageDiff {
+    pub language: String,
+    pub added: Vec<ValueChange>,
+    pub removed: Vec<ValueChange>,
+    pub changed: Vec<ValueChange>,
+}
+
+impl LanguageDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// 두 버전의 차이
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LocaleDiff {
+    /// 새 버전에만 있는 키 (어느 언어에든 값이 있는 키 기준)
+    pub added_keys: Vec<String>,
+    /// 이전 버전에만 있는 키
+    pub removed_keys: Vec<String>,
+    pub added_languages: Vec<String>,
+    pub removed_languages: Vec<String>,
+    /// 변경이 있는 언어만 (새 버전의 언어 순서, 삭제된 언어는 뒤에)
+    pub languages: Vec<LanguageDiff>,
+}
+
+/// 이전(`old`)과 새(`new`) 버전 비교
+pub fn diff_locales(old: &LocaleSnapshot, new: &LocaleSnapshot) -> LocaleDiff {
+    let old_keys = old.keys();
+    let new_keys = new.keys();
+
+    let mut languages: Vec<&String> = new.languages.iter().collect();
+    languages.extend(old.languages.iter().filter(|l| !new.languages.contains(l)));
+
+    let empty = BTreeMap::new();
+    let language_diffs = languages
+        .into_iter()
+        .map(|lang| {
+            let before = old.values.get(lang).unwrap_or(&empty);
+            let after = new.values.get(lang).unwrap_or(&empty);
+            let mut diff = LanguageDiff {
+                language: lang.clone(),
+                added: Vec::new(),
+                removed: Vec::new(),
+                changed: Vec::new(),
+            };
+
+            for (key, value) in after {
+                match before.get(key) {
+                    None => diff.added.push(ValueChange {
+                        key: key.clone(),
+                        old: None,
+                        new: Some(value.clone()),
+                    }),
+                    Some(previous) if previous != value => diff.changed.push(ValueChange {
+                        key: key.clone(),
+                        old: Some(previous.clone()),
+                        new: Some(value.clone()),
+                    }),
+                    Some(_) => {}
+                }
+            }
+            for (key, value) in before.iter().filter(|(k, _)| !after.contains_key(*k)) {
+                diff.removed.push(ValueChange {
+                    key: key.clone(),
+                    old: Some(value.clone()),
+                    new: None,
+                });
+            }
+            diff
+        })
+        .filter(|diff| !diff.is_empty())
+        .collect();
+
+    LocaleDiff {
+        added_keys: new_keys.difference(&old_keys).map(|k| k.to_string()).collect(),
+        removed_keys: old_keys.difference(&new_keys).map(|k| k.to_string()).collect(),
+        added_languages: new
+            .languages
+            .iter()
+            .filter(|l| !old.languages.contains(l))
+            .cloned()
+            .collect(),
+        removed_languages: old
+            .languages
+            .iter()
+            .filter(|l| !new.languages.contains(l))
+            .cloned()
+            .collect(),
+        languages: language_diffs,
+    }
+}
+
+impl LocaleDiff {
+    /// 변경이 전혀 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.added_languages.is_empty() && self.removed_languages.is_empty() && self.languages.is_empty()
+    }
+
+    /// PR 코멘트용 텍스트 (` ```diff ` 블록에 넣으면 +/- 줄이 색으로 구분됨)
+    ///
+    /// ```text
+    /// Keys: 1 added, 0 removed
+    ///
+    /// [ko] 1 added, 0 removed, 1 changed
+    /// + common.save = "저장"
+    /// - common.hello = "안녕"
+    /// + common.hello = "안녕하세요"
+    /// ```
+    pub fn to_text(&self) -> String {
+        if self.is_empty() {
+            return "No changes".to_string();
+        }
+
+        let mut out = format!(
+            "Keys: {} added, {} removed\n",
+            self.added_keys.len(),
+            self.removed_keys.len()
+        );
+        if !self.added_languages.is_empty() {
+            let _ = writeln!(out, "Languages added: {}", self.added_languages.join(", "));
+        }
+        if !self.removed_languages.is_empty() {
+            let _ = writeln!(out, "Languages removed: {}", self.removed_languages.join(", "));
+        }
+
+        for diff in &self.languages {
+            let _ = writeln!(
+                out,
+                "\n[{}] {} added, {} removed, {} changed",
+                diff.language,
+                diff.added.len(),
+                diff.removed.len(),
+                diff.changed.len()
+            );
+            for change in &diff.added {
+                let _ = writeln!(out, "+ {} = {:?}", change.key, change.new.as_deref().unwrap_or_default());
+            }
+            for change in &diff.removed {
+                let _ = writeln!(out, "- {} = {:?}", change.key, change.old.as_deref().unwrap_or_default());
+            }
+            for change in &diff.changed {
+                let _ = writeln!(out, "- {} = {:?}", change.key, change.old.as_deref().unwrap_or_default());
+                let _ = writeln!(out, "+ {} = {:?}", change.key, change.new.as_deref().unwrap_or_default());
+            }
+        }
+
+        out.trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(data: &str) -> LocaleSnapshot {
+        LocaleSnapshot::load(data.as_bytes(), DiffFormat::detect(data.as_bytes()), ".").unwrap()
+    }
+
+    #[test]
+    fn test_diff_csv() {
+        let old = snapshot("key,en,ko\nhello,Hello,안녕\nbye,Bye,잘가\nold,Old,옛날");
+        let new = snapshot("key,en,ko,ja\nhello,Hello,안녕하세요,\nbye,Bye,,\nsave,Save,저장,保存");
+
+        let diff = diff_locales(&old, &new);
+        assert_eq!(diff.added_keys, vec!["save"]);
+        assert_eq!(diff.removed_keys, vec!["old"]);
+        assert_eq!(diff.added_languages, vec!["ja"]);
+
+        let langs: Vec<&str> = diff.languages.iter().map(|d| d.language.as_str()).collect();
+        assert_eq!(langs, vec!["en", "ko", "ja"]);
+
+        let ko = &diff.languages[1];
+        assert_eq!(ko.added.len(), 1);
+        assert_eq!(
+            ko.changed,
+            vec![ValueChange {
+                key: "hello".into(),
+                old: Some("안녕".into()),
+                new: Some("안녕하세요".into()),
+            }]
+        );
+        let removed: Vec<&str> = ko.removed.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(removed, vec!["bye", "old"]);
+
+        let text = diff.to_text();
+        assert!(text.starts_with("Keys: 1 added, 1 removed\nLanguages added: ja\n"));
+        assert!(text.contains("[ko] 1 added, 2 removed, 1 changed\n+ save = \"저장\"\n- bye = \"잘가\""));
+        assert!(text.contains("- hello = \"안녕\"\n+ hello = \"안녕하세요\""));
+    }
+
+    #[test]
+    fn test_diff_json_against_csv() {
+        let old = snapshot("key,en\ncommon.hello,Hello");
+        let new = snapshot(r#"[{"language": "en", "content": "{\"common\": {\"hello\": \"Hello\"}}"}]"#);
+
+        let diff = diff_locales(&old, &new);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_text(), "No changes");
+
+        assert!(DiffFormat::resolve("yaml", b"").is_err());
+        assert_eq!(DiffFormat::resolve("", b"  [").unwrap(), DiffFormat::Json);
+    }
+}
@@ -5,28 +5,35 @@
 //! - Location (row, column, key)
 //! - Suggestions for fixing
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Result type alias using ParseError
 pub type Result<T> = std::result::Result<T, ParseError>;
 
 /// Main error type for parsing operations
-#[derive(Debug, Clone)]
+/// (also used for non-fatal warnings, see `ParseResult::warnings`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParseError {
     /// The kind of error
     pub kind: ErrorKind,
     /// Human-readable error message
     pub message: String,
     /// Optional location information
-    pub location: Option<ErrorLocation>,
+    /// (boxed to keep `Result<T, ParseError>` small)
+    pub location: Option<Box<ErrorLocation>>,
     /// Optional suggestion for fixing
     pub suggestion: Option<String>,
 }
 
+/// Row number of the header line in `ErrorLocation::row`
+pub const HEADER_ROW: usize = 1;
+
 /// Error location information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ErrorLocation {
-    /// Row number (1-based, 0 = header)
+    /// Line of the sheet or file (1-based, header = `HEADER_ROW`)
     pub row: Option<usize>,
     /// Column number (1-based)
     pub column: Option<usize>,
@@ -37,13 +44,16 @@ pub struct ErrorLocation {
 }
 
 /// Specific error types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ErrorKind {
     // Header errors
     EmptyData,
     InvalidKeyColumn,
     NoLanguageColumns,
     MixedSeparators,
+    UnknownLanguageCode,
+    DuplicateLanguageColumn,
     
     // CSV parsing errors
     CsvParseError,
@@ -54,18 +64,36 @@ pub enum ErrorKind {
     EmptyWorkbook,
     EmptySheet,
     WorksheetReadError,
+    SheetNotFound,
+    
+    // Translation file format errors
+    PoParseError,
+    XliffParseError,
+    AndroidResourceError,
+    XcstringsParseError,
+    ArbParseError,
     
     // Data validation errors
     DuplicateKey,
     InvalidKeyFormat,
     MissingTranslation,
+    InvalidMetadataValue,
+    MaxLengthExceeded,
     ColumnCountMismatch,
+    PlaceholderMissing,
+    PlaceholderExtra,
+    PlaceholderRenamed,
+    PlaceholderTypeMismatch,
+    TagUnbalanced,
+    TagMissing,
+    TagExtra,
     
     // Conversion errors
     NestedKeyConflict,
     JsonParseError,
     JsonSerializeError,
     YamlSerializeError,
+    XlsxWriteError,
     
     // Generic errors
     IoError,
@@ -85,7 +113,7 @@ impl ParseError {
 
     /// Add location information
     pub fn with_location(mut self, location: ErrorLocation) -> Self {
-        self.location = Some(location);
+        self.location = Some(Box::new(location));
         self
     }
 
@@ -100,12 +128,12 @@ impl ParseError {
         if let Some(ref mut loc) = self.location {
             loc.row = Some(row);
         } else {
-            self.location = Some(ErrorLocation {
+            self.location = Some(Box::new(ErrorLocation {
                 row: Some(row),
                 column: None,
                 column_name: None,
                 key: None,
-            });
+            }));
         }
         self
     }
@@ -116,12 +144,12 @@ impl ParseError {
             loc.column = Some(column);
             loc.column_name = name;
         } else {
-            self.location = Some(ErrorLocation {
+            self.location = Some(Box::new(ErrorLocation {
                 row: None,
                 column: Some(column),
                 column_name: name,
                 key: None,
-            });
+            }));
         }
         self
     }
@@ -131,12 +159,12 @@ impl ParseError {
         if let Some(ref mut loc) = self.location {
             loc.key = Some(key.into());
         } else {
-            self.location = Some(ErrorLocation {
+            self.location = Some(Box::new(ErrorLocation {
                 row: None,
                 column: None,
                 column_name: None,
                 key: Some(key.into()),
-            });
+            }));
         }
         self
     }
@@ -160,7 +188,7 @@ impl ParseError {
             format!("Invalid header: first column must be 'key', but found '{}'", found),
         )
         .with_location(ErrorLocation {
-            row: Some(0),
+            row: Some(HEADER_ROW),
             column: Some(1),
             column_name: Some(found.to_string()),
             key: None,
@@ -174,7 +202,7 @@ impl ParseError {
             ErrorKind::NoLanguageColumns,
             "No language columns found in header",
         )
-        .at_row(0)
+        .at_row(HEADER_ROW)
         .with_suggestion("Add language columns after 'key' column (e.g., 'en', 'ko', 'ja')")
     }
 
@@ -208,9 +236,42 @@ impl ParseError {
         err
     }
 
+    /// Header column that is not a known language code (warning)
+    pub fn unknown_language_code(code: &str, column: usize) -> Self {
+        Self::new(
+            ErrorKind::UnknownLanguageCode,
+            format!("Column '{}' is not a known language code", code),
+        )
+        .with_location(ErrorLocation {
+            row: Some(HEADER_ROW),
+            column: Some(column),
+            column_name: Some(code.to_string()),
+            key: None,
+        })
+        .with_suggestion("Use an ISO 639-1 code, optionally with a region (e.g., 'en', 'pt-BR')")
+    }
+
+    /// Same language appears in more than one header column (warning)
+    pub fn duplicate_language_column(code: &str, first_column: usize, column: usize) -> Self {
+        Self::new(
+            ErrorKind::DuplicateLanguageColumn,
+            format!(
+                "Language '{}' appears again in column {} (first in column {}); the later column is ignored",
+                code, column, first_column
+            ),
+        )
+        .with_location(ErrorLocation {
+            row: Some(HEADER_ROW),
+            column: Some(column),
+            column_name: Some(code.to_string()),
+            key: None,
+        })
+        .with_suggestion("Remove or rename the duplicate language column")
+    }
+
     /// Column count mismatch (row has different number of columns than header)
     pub fn column_count_mismatch(row: usize, expected: usize, found: usize) -> Self {
-        // row is 1-based including header (caller should supply)
+        // row is the physical line (1-based, header = HEADER_ROW)
         let msg = format!(
             "Column count mismatch: expected {} columns (from header), but found {} (row {})",
             expected, found, row
@@ -292,6 +353,81 @@ impl ParseError {
         )
     }
 
+    /// Requested sheet does not exist
+    pub fn sheet_not_found(requested: &str, available: &[String]) -> Self {
+        Self::new(
+            ErrorKind::SheetNotFound,
+            format!("Sheet '{}' not found in workbook", requested),
+        )
+        .with_suggestion(format!("Available sheets: {}", available.join(", ")))
+    }
+
+    /// Sheet name used as a key namespace contains a different key separator
+    pub fn mixed_separators_in_sheet_name(found: Vec<char>, expected: &str) -> Self {
+        let mut separators: Vec<String> = found.iter().map(|c| c.to_string()).collect();
+        separators.sort();
+        separators.dedup();
+
+        Self::new(
+            ErrorKind::MixedSeparators,
+            format!(
+                "Mixed key separators found in sheet name: expected '{}', but found: {}",
+                expected,
+                separators.join(", ")
+            ),
+        )
+        .with_suggestion(format!(
+            "Rename the sheet so it uses only '{}' (the sheet name becomes the first key segment)",
+            expected
+        ))
+    }
+
+    /// Gettext PO/POT syntax error
+    pub fn po_parse_error(line: usize, details: &str) -> Self {
+        Self::new(
+            ErrorKind::PoParseError,
+            format!("PO parsing error: {} (line {})", details, line),
+        )
+        .at_row(line.saturating_sub(1))
+        .with_suggestion("Check that every msgid/msgstr value is a double-quoted string")
+    }
+
+    /// XLIFF document error
+    pub fn xliff_parse_error(details: &str) -> Self {
+        Self::new(
+            ErrorKind::XliffParseError,
+            format!("XLIFF parsing error: {}", details),
+        )
+        .with_suggestion("Ensure the file is a well-formed XLIFF 1.2 or 2.0 document")
+    }
+
+    /// Android `strings.xml` error
+    pub fn android_resource_error(path: &str, details: &str) -> Self {
+        Self::new(
+            ErrorKind::AndroidResourceError,
+            format!("Invalid Android resource '{}': {}", path, details),
+        )
+        .with_suggestion("Ensure the file is a valid res/values*/strings.xml resource file")
+    }
+
+    /// `.xcstrings` (String Catalog) structure error
+    pub fn xcstrings_parse_error(details: &str) -> Self {
+        Self::new(
+            ErrorKind::XcstringsParseError,
+            format!("String Catalog parsing error: {}", details),
+        )
+        .with_suggestion("Ensure the file is a Localizable.xcstrings file saved by Xcode")
+    }
+
+    /// Flutter ARB file error
+    pub fn arb_parse_error(context: &str, details: &str) -> Self {
+        Self::new(
+            ErrorKind::ArbParseError,
+            format!("Invalid ARB file '{}': {}", context, details),
+        )
+        .with_suggestion("Ensure the file is a Flutter .arb file with an '@@locale' entry")
+    }
+
     /// Duplicate key found
     pub fn duplicate_key(key: &str, first_row: usize, duplicate_row: usize) -> Self {
         Self::new(
@@ -317,29 +453,161 @@ impl ParseError {
     }
 
     /// Missing translation
-    pub fn missing_translation(key: &str, language: &str, row: usize) -> Self {
-        Self::new(
+    pub fn missing_translation(key: &str, language: &str, row: Option<usize>, column: Option<usize>) -> Self {
+        let err = Self::new(
             ErrorKind::MissingTranslation,
-            format!(
-                "Missing translation for key '{}' in language '{}' at row {}",
-                key, language, row
-            ),
+            format!("Missing translation for key '{}' in language '{}'", key, language),
+        )
+            .with_location(Self::translation_location(key, language, column))
+            .with_suggestion("Fill in the empty cell or turn off strict mode");
+        match row {
+            Some(row) => err.at_row(row),
+            None => err,
+        }
+    }
+
+    /// Metadata cell that cannot be read, e.g. a non-numeric `#maxLength` (warning, value is ignored)
+    pub fn invalid_metadata_value(key: &str, column_name: &str, value: &str, row: usize, column: usize) -> Self {
+        Self::new(
+            ErrorKind::InvalidMetadataValue,
+            format!("Invalid {} value '{}' for key '{}'", column_name, value, key),
         )
         .with_key(key)
+        .at_column(column, Some(column_name.to_string()))
         .at_row(row)
-        .at_column(0, Some(language.to_string()))
+        .with_suggestion("Use a positive whole number or leave the cell empty")
     }
 
-    /// Nested key conflict
-    pub fn nested_key_conflict(key1: &str, key2: &str) -> Self {
+    /// Location of one translation cell (key row, language column)
+    fn translation_location(key: &str, language: &str, column: Option<usize>) -> ErrorLocation {
+        ErrorLocation {
+            row: None,
+            column,
+            column_name: Some(language.to_string()),
+            key: Some(key.to_string()),
+        }
+    }
+
+    /// Translation longer than the maximum length of its key
+    pub fn max_length_exceeded(
+        key: &str,
+        language: &str,
+        column: Option<usize>,
+        max_length: usize,
+        length: usize,
+    ) -> Self {
         Self::new(
+            ErrorKind::MaxLengthExceeded,
+            format!("Key '{}' ({}): length {} exceeds the limit of {}", key, language, length, max_length),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Shorten the translation by {}", length - max_length))
+    }
+
+    /// Source placeholder not found in a translation
+    pub fn placeholder_missing(key: &str, language: &str, column: Option<usize>, placeholder: &str) -> Self {
+        Self::new(
+            ErrorKind::PlaceholderMissing,
+            format!("Key '{}' ({}): placeholder {} is missing", key, language, placeholder),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Add {} to the translation", placeholder))
+    }
+
+    /// Translation has a placeholder the source does not have
+    pub fn placeholder_extra(key: &str, language: &str, column: Option<usize>, placeholder: &str) -> Self {
+        Self::new(
+            ErrorKind::PlaceholderExtra,
+            format!("Key '{}' ({}): placeholder {} is not in the source text", key, language, placeholder),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Remove {} or add it to the source text", placeholder))
+    }
+
+    /// Placeholder of the same kind but a different name (e.g. `{{nmae}}` for `{{name}}`)
+    pub fn placeholder_renamed(
+        key: &str,
+        language: &str,
+        column: Option<usize>,
+        expected: &str,
+        found: &str,
+    ) -> Self {
+        Self::new(
+            ErrorKind::PlaceholderRenamed,
+            format!("Key '{}' ({}): placeholder {} was found instead of {}", key, language, found, expected),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Rename {} to {}", found, expected))
+    }
+
+    /// Placeholder with the same name but different syntax (e.g. `{name}` for `{{name}}`)
+    pub fn placeholder_type_mismatch(
+        key: &str,
+        language: &str,
+        column: Option<usize>,
+        expected: &str,
+        found: &str,
+    ) -> Self {
+        Self::new(
+            ErrorKind::PlaceholderTypeMismatch,
+            format!("Key '{}' ({}): placeholder {} should be written as {}", key, language, found, expected),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Use the source syntax {}", expected))
+    }
+
+    /// Markup in one string is not well-formed (unclosed, unopened or misnested tag)
+    pub fn tag_unbalanced(key: &str, language: &str, column: Option<usize>, details: &str) -> Self {
+        Self::new(
+            ErrorKind::TagUnbalanced,
+            format!("Key '{}' ({}): {}", key, language, details),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion("Make sure every opening tag has a matching closing tag in the right order")
+    }
+
+    /// Source tag not found in a translation
+    pub fn tag_missing(key: &str, language: &str, column: Option<usize>, tag: &str) -> Self {
+        Self::new(
+            ErrorKind::TagMissing,
+            format!("Key '{}' ({}): tag {} from the source text is missing", key, language, tag),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Keep {} around the matching part of the translation", tag))
+    }
+
+    /// Translation has a tag the source does not have
+    pub fn tag_extra(key: &str, language: &str, column: Option<usize>, tag: &str) -> Self {
+        Self::new(
+            ErrorKind::TagExtra,
+            format!("Key '{}' ({}): tag {} is not in the source text", key, language, tag),
+        )
+        .with_location(Self::translation_location(key, language, column))
+        .with_suggestion(format!("Remove {} or add it to the source text", tag))
+    }
+
+    /// Nested key conflict (`key2`/`row2` is where it was found; rows are known for CSV/Excel input)
+    pub fn nested_key_conflict(key1: &str, row1: Option<usize>, key2: &str, row2: Option<usize>) -> Self {
+        let describe = |key: &str, row: Option<usize>| match row {
+            Some(row) => format!("'{}' (row {})", key, row),
+            None => format!("'{}'", key),
+        };
+        let err = Self::new(
             ErrorKind::NestedKeyConflict,
             format!(
-                "Nested key conflict: '{}' and '{}' cannot coexist (one is a prefix of the other)",
-                key1, key2
+                "Nested key conflict: {} and {} cannot coexist (one is a prefix of the other)",
+                describe(key1, row1),
+                describe(key2, row2)
             ),
         )
-        .with_suggestion("Rename one of the keys to avoid the conflict")
+        .with_key(key2)
+        .with_suggestion(
+            "Rename one of the keys, use flat output, or set a leaf key (e.g. \"_\") to keep both",
+        );
+        match row2 {
+            Some(row) => err.at_row(row),
+            None => err,
+        }
     }
 
     /// JSON serialization error
@@ -376,6 +644,14 @@ impl ParseError {
         )
     }
 
+    /// Failed to write an xlsx workbook
+    pub fn xlsx_write_error(details: &str) -> Self {
+        Self::new(
+            ErrorKind::XlsxWriteError,
+            format!("Failed to write xlsx workbook: {}", details),
+        )
+    }
+
     /// Generic IO error
     pub fn io_error(err: &std::io::Error) -> Self {
         Self::new(ErrorKind::IoError, format!("IO error: {}", err))
@@ -393,7 +669,7 @@ impl fmt::Display for ParseError {
         if let Some(ref loc) = self.location {
             let mut parts = Vec::new();
             if let Some(row) = loc.row {
-                parts.push(format!("row {}", row + 1)); // Convert to 1-based
+                parts.push(format!("row {}", row));
             }
             if let Some(col) = loc.column {
                 if let Some(ref name) = loc.column_name {
@@ -425,20 +701,38 @@ impl fmt::Display for ErrorKind {
             ErrorKind::InvalidKeyColumn => "INVALID_KEY_COLUMN",
             ErrorKind::NoLanguageColumns => "NO_LANGUAGE_COLUMNS",
             ErrorKind::MixedSeparators => "MIXED_SEPARATORS",
+            ErrorKind::UnknownLanguageCode => "UNKNOWN_LANGUAGE_CODE",
+            ErrorKind::DuplicateLanguageColumn => "DUPLICATE_LANGUAGE_COLUMN",
             ErrorKind::CsvParseError => "CSV_PARSE_ERROR",
             ErrorKind::Utf8Error => "UTF8_ERROR",
             ErrorKind::ExcelOpenError => "EXCEL_OPEN_ERROR",
             ErrorKind::EmptyWorkbook => "EMPTY_WORKBOOK",
             ErrorKind::EmptySheet => "EMPTY_SHEET",
             ErrorKind::WorksheetReadError => "WORKSHEET_READ_ERROR",
+            ErrorKind::SheetNotFound => "SHEET_NOT_FOUND",
+            ErrorKind::PoParseError => "PO_PARSE_ERROR",
+            ErrorKind::XliffParseError => "XLIFF_PARSE_ERROR",
+            ErrorKind::AndroidResourceError => "ANDROID_RESOURCE_ERROR",
+            ErrorKind::XcstringsParseError => "XCSTRINGS_PARSE_ERROR",
+            ErrorKind::ArbParseError => "ARB_PARSE_ERROR",
             ErrorKind::DuplicateKey => "DUPLICATE_KEY",
             ErrorKind::InvalidKeyFormat => "INVALID_KEY_FORMAT",
             ErrorKind::MissingTranslation => "MISSING_TRANSLATION",
+            ErrorKind::InvalidMetadataValue => "INVALID_METADATA_VALUE",
+            ErrorKind::MaxLengthExceeded => "MAX_LENGTH_EXCEEDED",
             ErrorKind::ColumnCountMismatch => "COLUMN_COUNT_MISMATCH",
+            ErrorKind::PlaceholderMissing => "PLACEHOLDER_MISSING",
+            ErrorKind::PlaceholderExtra => "PLACEHOLDER_EXTRA",
+            ErrorKind::PlaceholderRenamed => "PLACEHOLDER_RENAMED",
+            ErrorKind::PlaceholderTypeMismatch => "PLACEHOLDER_TYPE_MISMATCH",
+            ErrorKind::TagUnbalanced => "TAG_UNBALANCED",
+            ErrorKind::TagMissing => "TAG_MISSING",
+            ErrorKind::TagExtra => "TAG_EXTRA",
             ErrorKind::NestedKeyConflict => "NESTED_KEY_CONFLICT",
             ErrorKind::JsonParseError => "JSON_PARSE_ERROR",
             ErrorKind::JsonSerializeError => "JSON_SERIALIZE_ERROR",
             ErrorKind::YamlSerializeError => "YAML_SERIALIZE_ERROR",
+            ErrorKind::XlsxWriteError => "XLSX_WRITE_ERROR",
             ErrorKind::IoError => "IO_ERROR",
             ErrorKind::Unknown => "UNKNOWN_ERROR",
         };
@@ -492,13 +786,18 @@ impl From<calamine::Error> for ParseError {
 impl ParseError {
     /// Convert to JSON string for WASM
     pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Convert to a JSON value (same shape as `to_json`)
+    pub fn to_json_value(&self) -> serde_json::Value {
         serde_json::json!({
             "error": true,
             "kind": self.kind.to_string(),
             "message": self.message,
             "location": self.location.as_ref().map(|loc| {
                 serde_json::json!({
-                    "row": loc.row.map(|r| r + 1), // 1-based for display
+                    "row": loc.row,
                     "column": loc.column,
                     "columnName": loc.column_name,
                     "key": loc.key,
@@ -507,7 +806,6 @@ impl ParseError {
             "suggestion": self.suggestion,
             "fullMessage": self.to_string(),
         })
-        .to_string()
     }
 }
 
@@ -529,7 +827,7 @@ mod tests {
         assert_eq!(err.kind, ErrorKind::InvalidKeyColumn);
         assert!(err.location.is_some());
         let loc = err.location.as_ref().unwrap();
-        assert_eq!(loc.row, Some(0));
+        assert_eq!(loc.row, Some(HEADER_ROW));
         println!("{}", err);
     }
 
@@ -546,7 +844,15 @@ mod tests {
         let json = err.to_json();
         println!("{}", json);
         assert!(json.contains("INVALID_KEY_COLUMN"));
-        assert!(json.contains("\"row\":1")); // 1-based
+        assert!(json.contains("\"row\":1")); // header line
+    }
+
+    #[test]
+    fn test_error_kind_serde_matches_display() {
+        for kind in [ErrorKind::Utf8Error, ErrorKind::UnknownLanguageCode, ErrorKind::XcstringsParseError] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind));
+        }
     }
 
     #[test]
@@ -557,7 +863,7 @@ mod tests {
             .with_suggestion("Remove spaces from key names");
 
         println!("{}", err);
-        assert!(err.to_string().contains("row 11")); // 1-based
+        assert!(err.to_string().contains("row 10"));
         assert!(err.to_string().contains("hello world"));
     }
 }
@@ -1,8 +1,10 @@
 use crate::error::{ParseError, Result};
 use crate::lang_codes::normalize_lang_code;
+use crate::transform::{merge_key_order, order_keys, parse_typed_value};
+use crate::types::KeyOrder;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Input for each language JSON payload
 #[derive(Debug, Clone, Deserialize)]
@@ -16,6 +18,17 @@ pub struct LangJsonInput {
     pub content: String,
 }
 
+/// Options for merging JSON locale files into a table
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct MergeOptions {
+    /// Row order
+    pub order: KeyOrder,
+    /// Write cells so that `ParseOptions::typed_values` reads them back with the same type:
+    /// strings that look like numbers, booleans, null or arrays get a `'` prefix and null is written as `null`
+    pub typed_values: bool,
+}
+
 /// Table representation used to build CSV/Excel
 #[derive(Debug, Clone, Serialize)]
 pub struct TableData {
@@ -24,7 +37,7 @@ pub struct TableData {
 }
 
 /// Flatten a JSON value into dot-separated keys
-fn flatten_value(prefix: &str, value: &Value, separator: &str, out: &mut BTreeMap<String, Value>) {
+pub(crate) fn flatten_value(prefix: &str, value: &Value, separator: &str, out: &mut impl Extend<(String, Value)>) {
     match value {
         Value::Object(map) => {
             for (k, v) in map {
@@ -37,12 +50,12 @@ fn flatten_value(prefix: &str, value: &Value, separator: &str, out: &mut BTreeMa
             }
         }
         other => {
-            out.insert(prefix.to_string(), other.clone());
+            out.extend([(prefix.to_string(), other.clone())]);
         }
     }
 }
 
-fn value_to_cell(value: &Value) -> String {
+pub(crate) fn value_to_cell(value: &Value) -> String {
     match value {
         Value::String(s) => s.clone(),
         Value::Null => String::new(),
@@ -50,9 +63,47 @@ fn value_to_cell(value: &Value) -> String {
     }
 }
 
+/// Cell text for typed output (see `MergeOptions::typed_values`)
+fn typed_value_to_cell(value: &Value) -> String {
+    match value {
+        Value::String(s) if parse_typed_value(s).is_some() => format!("'{}", s),
+        Value::Null => "null".to_string(),
+        other => value_to_cell(other),
+    }
+}
+
+/// Flatten one language of `LocaleData` back into separator-joined keys.
+pub fn flatten_locale(data: &serde_json::Map<String, Value>, separator: &str) -> BTreeMap<String, Value> {
+    let mut flat = BTreeMap::new();
+    for (key, value) in data {
+        flatten_value(key, value, separator, &mut flat);
+    }
+    flat
+}
+
 /// Merge multiple JSON locale files into a tabular form.
-/// Missing translations are filled with an empty string.
+/// Missing translations are filled with an empty string and rows are sorted alphabetically.
 pub fn merge_jsons_to_table(inputs: &[LangJsonInput], separator: &str) -> Result<TableData> {
+    merge_jsons_to_table_with_order(inputs, separator, KeyOrder::Alphabetical)
+}
+
+/// Same as `merge_jsons_to_table`, but rows follow `order`.
+/// With `KeyOrder::Source` they keep the order of the JSON files
+/// (keys only present in later files are placed after the key that precedes them there).
+pub fn merge_jsons_to_table_with_order(
+    inputs: &[LangJsonInput],
+    separator: &str,
+    order: KeyOrder,
+) -> Result<TableData> {
+    merge_jsons_to_table_with_options(inputs, separator, &MergeOptions { order, ..Default::default() })
+}
+
+/// Same as `merge_jsons_to_table`, with row order and typed output from `options`.
+pub fn merge_jsons_to_table_with_options(
+    inputs: &[LangJsonInput],
+    separator: &str,
+    options: &MergeOptions,
+) -> Result<TableData> {
     if inputs.is_empty() {
         return Err(ParseError::empty_data().with_suggestion(
             "Provide at least one JSON input with language and content",
@@ -61,7 +112,7 @@ pub fn merge_jsons_to_table(inputs: &[LangJsonInput], separator: &str) -> Result
 
     let mut languages: Vec<String> = Vec::with_capacity(inputs.len());
     let mut seen = HashSet::new();
-    let mut lang_data: HashMap<String, BTreeMap<String, Value>> = HashMap::new();
+    let mut lang_data: HashMap<String, Map<String, Value>> = HashMap::new();
 
     for input in inputs {
         let lang = normalize_lang_code(&input.language);
@@ -81,18 +132,21 @@ pub fn merge_jsons_to_table(inputs: &[LangJsonInput], separator: &str) -> Result
             .as_object()
             .ok_or_else(|| ParseError::invalid_json_root(&input.language))?;
 
-        let mut flat = BTreeMap::new();
+        let mut flat = Map::new();
         flatten_value("", &Value::Object(root.clone()), separator, &mut flat);
         lang_data.insert(lang, flat);
     }
 
-    // Collect all keys across languages (sorted)
-    let mut all_keys: BTreeSet<String> = BTreeSet::new();
-    for map in lang_data.values() {
-        for key in map.keys() {
-            all_keys.insert(key.clone());
-        }
+    // Collect all keys across languages (in input order, then ordered)
+    let mut all_keys: Vec<String> = Vec::new();
+    for lang in &languages {
+        all_keys = merge_key_order(&all_keys, lang_data[lang].keys())
+            .into_iter()
+            .cloned()
+            .collect();
     }
+    order_keys(&mut all_keys, options.order);
+    let to_cell = if options.typed_values { typed_value_to_cell } else { value_to_cell };
 
     // Build rows: key + per-language value
     let mut rows: Vec<Vec<String>> = Vec::with_capacity(all_keys.len());
@@ -103,7 +157,7 @@ pub fn merge_jsons_to_table(inputs: &[LangJsonInput], separator: &str) -> Result
             let val = lang_data
                 .get(lang)
                 .and_then(|m| m.get(&key))
-                .map(value_to_cell)
+                .map(to_cell)
                 .unwrap_or_default();
             row.push(val);
         }
@@ -119,7 +173,17 @@ pub fn merge_jsons_to_table(inputs: &[LangJsonInput], separator: &str) -> Result
 
 /// Convert the merged table into a CSV string.
 pub fn merge_jsons_to_csv(inputs: &[LangJsonInput], separator: &str) -> Result<String> {
-    let table = merge_jsons_to_table(inputs, separator)?;
+    merge_jsons_to_csv_with_order(inputs, separator, KeyOrder::Alphabetical)
+}
+
+/// Same as `merge_jsons_to_csv`, but rows follow `order`.
+pub fn merge_jsons_to_csv_with_order(inputs: &[LangJsonInput], separator: &str, order: KeyOrder) -> Result<String> {
+    merge_jsons_to_csv_with_options(inputs, separator, &MergeOptions { order, ..Default::default() })
+}
+
+/// Same as `merge_jsons_to_csv`, with row order and typed output from `options`.
+pub fn merge_jsons_to_csv_with_options(inputs: &[LangJsonInput], separator: &str, options: &MergeOptions) -> Result<String> {
+    let table = merge_jsons_to_table_with_options(inputs, separator, options)?;
     let mut writer = csv::Writer::from_writer(Vec::new());
 
     writer
@@ -134,11 +198,34 @@ pub fn merge_jsons_to_csv(inputs: &[LangJsonInput], separator: &str) -> Result<S
 
     let data = writer
         .into_inner()
-        .map_err(|e| ParseError::io_error(&std::io::Error::new(std::io::ErrorKind::Other, e.to_string())))?;
+        .map_err(|e| ParseError::io_error(&std::io::Error::other(e.to_string())))?;
 
     String::from_utf8(data).map_err(|e| ParseError::utf8_error(&e.utf8_error()))
 }
 
+/// Convert the merged table into an xlsx workbook (frozen bold header, missing cells highlighted).
+#[cfg(feature = "xlsx")]
+pub fn merge_jsons_to_xlsx(inputs: &[LangJsonInput], separator: &str) -> Result<Vec<u8>> {
+    merge_jsons_to_xlsx_with_order(inputs, separator, KeyOrder::Alphabetical)
+}
+
+/// Same as `merge_jsons_to_xlsx`, but rows follow `order`.
+#[cfg(feature = "xlsx")]
+pub fn merge_jsons_to_xlsx_with_order(inputs: &[LangJsonInput], separator: &str, order: KeyOrder) -> Result<Vec<u8>> {
+    merge_jsons_to_xlsx_with_options(inputs, separator, &MergeOptions { order, ..Default::default() })
+}
+
+/// Same as `merge_jsons_to_xlsx`, with row order and typed output from `options`.
+#[cfg(feature = "xlsx")]
+pub fn merge_jsons_to_xlsx_with_options(
+    inputs: &[LangJsonInput],
+    separator: &str,
+    options: &MergeOptions,
+) -> Result<Vec<u8>> {
+    let table = merge_jsons_to_table_with_options(inputs, separator, options)?;
+    crate::xlsx::table_to_xlsx(&table)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +273,28 @@ mod tests {
         assert!(csv.contains("app.hello,Hello,안녕"));
     }
 
+    #[test]
+    fn keeps_source_key_order() {
+        let inputs = vec![
+            LangJsonInput {
+                language: "en".to_string(),
+                content: r#"{"title":"Title","save":"Save","item10":"Ten","item2":"Two"}"#.to_string(),
+            },
+            LangJsonInput {
+                language: "ko".to_string(),
+                content: r#"{"title":"제목","subtitle":"부제","item2":"둘"}"#.to_string(),
+            },
+        ];
+
+        let keys = |order: KeyOrder| -> Vec<String> {
+            let table = merge_jsons_to_table_with_order(&inputs, ".", order).unwrap();
+            table.rows.into_iter().map(|row| row[0].clone()).collect()
+        };
+        // keys only in later languages go right after their predecessor
+        assert_eq!(keys(KeyOrder::Source), vec!["title", "subtitle", "save", "item10", "item2"]);
+        assert_eq!(keys(KeyOrder::Natural), vec!["item2", "item10", "save", "subtitle", "title"]);
+    }
+
     #[test]
     fn rejects_non_object_root() {
         let inputs = vec![LangJsonInput {
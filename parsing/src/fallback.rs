@@ -0,0 +1,206 @@
+//! 언어 fallback 체인
+//!
+//! 번역이 비어 있는 키를 다른 언어의 값으로 채웁니다.
+//! 기본 체인은 `지역 언어 -> 기본 언어 -> 기본값 언어`입니다.
+//! 예: 시트에 `en`, `pt`, `pt-BR`이 있으면 `pt-BR`은 `pt`, 그다음 `en`에서 값을 가져옵니다.
+
+use crate::export::flatten_value;
+use crate::lang_codes::{base_lang_code, normalize_lang_code};
+use crate::transform::merge_key_order;
+use crate::types::ParseResult;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// fallback 설정
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FallbackOptions {
+    /// 체인의 마지막 언어 (없으면 첫 번째 언어 컬럼)
+    pub default_language: Option<String>,
+    /// 언어별 체인 직접 지정 (기본 체인 대신 사용)
+    /// 예: `{ "pt-BR": ["pt-PT", "es", "en"] }`
+    pub overrides: BTreeMap<String, Vec<String>>,
+}
+
+/// fallback 적용 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FallbackResult {
+    /// 빈 값이 채워진 결과
+    pub result: ParseResult,
+    /// 다른 언어에서 가져온 값: 언어 -> (flat 키 -> 가져온 언어)
+    pub inherited: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// 한 언어의 fallback 체인 (자기 자신 제외, `languages`에 있는 언어만)
+pub fn fallback_chain(language: &str, languages: &[String], options: &FallbackOptions) -> Vec<String> {
+    let language = normalize_lang_code(language);
+    let override_chain = options
+        .overrides
+        .iter()
+        .find(|(lang, _)| normalize_lang_code(lang) == language)
+        .map(|(_, chain)| chain.clone());
+
+    let candidates = override_chain.unwrap_or_else(|| {
+        let default = options
+            .default_language
+            .clone()
+            .or_else(|| languages.first().cloned());
+        std::iter::once(base_lang_code(&language)).chain(default).collect()
+    });
+
+    let mut chain: Vec<String> = Vec::new();
+    for candidate in candidates.iter().map(|c| normalize_lang_code(c)) {
+        if candidate != language && languages.contains(&candidate) && !chain.contains(&candidate) {
+            chain.push(candidate);
+        }
+    }
+    chain
+}
+
+/// 모든 언어의 빈 값을 fallback 체인 순서대로 채우기
+///
+/// 체인의 각 언어는 원래 값만 사용합니다 (채워진 값을 다시 전달하지 않음).
+/// 빈 문자열은 값이 없는 것으로 취급하고, `separator`는 `inherited`의 키를 만들 때 사용합니다.
+pub fn fill_fallbacks(result: &ParseResult, options: &FallbackOptions, separator: &str) -> FallbackResult {
+    let mut filled = result.clone();
+    let mut inherited = BTreeMap::new();
+
+    for language in &result.languages {
+        let mut target: Map<String, Value> = result
+            .data
+            .get(language)
+            .map(|data| data.clone().into_iter().collect())
+            .unwrap_or_default();
+        let mut marks = BTreeMap::new();
+
+        for source in fallback_chain(language, &result.languages, options) {
+            let Some(source_data) = result.data.get(&source) else {
+                continue;
+            };
+            fill_map(&mut target, source_data, "", &source, separator, &mut marks);
+        }
+
+        if !marks.is_empty() {
+            inherited.insert(language.clone(), marks);
+        }
+        filled.data.insert(language.clone(), target.into_iter().collect());
+    }
+
+    FallbackResult {
+        result: filled,
+        inherited,
+    }
+}
+
+/// `target`의 빈 값을 `source`로 채우고, 둘 다 object인 키는 재귀적으로 채움
+/// 새로 생긴 키는 `source`에서 바로 앞에 있던 키 뒤에 넣어 기존 키 순서를 유지합니다.
+/// (`path`는 `inherited`에 기록할 flat 키의 접두어)
+fn fill_map(
+    target: &mut Map<String, Value>,
+    source: &Map<String, Value>,
+    path: &str,
+    source_language: &str,
+    separator: &str,
+    marks: &mut BTreeMap<String, String>,
+) {
+    let original: Vec<String> = target.keys().cloned().collect();
+    for (key, value) in source {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}{}{}", path, separator, key)
+        };
+        match (target.get_mut(key), value) {
+            (Some(Value::Object(existing)), Value::Object(children)) => {
+                fill_map(existing, children, &key_path, source_language, separator, marks);
+            }
+            (None | Some(Value::Null), _) => {
+                mark_leaves(&key_path, value, source_language, separator, marks);
+                target.insert(key.clone(), value.clone());
+            }
+            (Some(Value::String(s)), _) if s.is_empty() => {
+                mark_leaves(&key_path, value, source_language, separator, marks);
+                target.insert(key.clone(), value.clone());
+            }
+            _ => {}
+        }
+    }
+
+    if target.len() > original.len() {
+        let mut entries = std::mem::take(target);
+        for key in merge_key_order(&original, source.keys()) {
+            if let Some(value) = entries.remove(key) {
+                target.insert(key.clone(), value);
+            }
+        }
+    }
+}
+
+/// 가져온 값의 모든 leaf 키를 `source` 언어에서 온 것으로 기록
+fn mark_leaves(path: &str, value: &Value, source: &str, separator: &str, marks: &mut BTreeMap<String, String>) {
+    let mut leaves = BTreeMap::new();
+    flatten_value(path, value, separator, &mut leaves);
+    for key in leaves.into_keys() {
+        marks.insert(key, source.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::types::ParseOptions;
+
+    fn languages(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let langs = languages(&["en", "pt", "pt-BR", "es"]);
+        let options = FallbackOptions::default();
+
+        assert_eq!(fallback_chain("pt-BR", &langs, &options), vec!["pt", "en"]);
+        assert_eq!(fallback_chain("pt", &langs, &options), vec!["en"]);
+        assert!(fallback_chain("en", &langs, &options).is_empty());
+
+        let options = FallbackOptions {
+            default_language: Some("es".to_string()),
+            overrides: BTreeMap::from([("pt_br".to_string(), vec!["es".to_string(), "xx".to_string()])]),
+        };
+        assert_eq!(fallback_chain("pt-BR", &langs, &options), vec!["es"]);
+        assert_eq!(fallback_chain("pt", &langs, &options), vec!["es"]);
+    }
+
+    #[test]
+    fn test_fill_fallbacks() {
+        let csv = "key,en,pt,pt-BR\n\
+                   common.hello,Hello,Olá,Oi\n\
+                   common.bye,Bye,Tchau,\n\
+                   common.save,Save,,\n\
+                   title,Title,,";
+        let result = parser::csv::parse(csv.as_bytes(), &ParseOptions::default()).unwrap();
+        let filled = fill_fallbacks(&result, &FallbackOptions::default(), ".");
+
+        let pt_br = &filled.result.data["pt-BR"];
+        assert_eq!(pt_br["common"]["hello"], "Oi");
+        assert_eq!(pt_br["common"]["bye"], "Tchau");
+        assert_eq!(pt_br["common"]["save"], "Save");
+        assert_eq!(pt_br["title"], "Title");
+
+        assert_eq!(
+            filled.inherited["pt-BR"],
+            BTreeMap::from([
+                ("common.bye".to_string(), "pt".to_string()),
+                ("common.save".to_string(), "en".to_string()),
+                ("title".to_string(), "en".to_string()),
+            ])
+        );
+        assert_eq!(filled.inherited["pt"].len(), 2);
+        assert!(!filled.inherited.contains_key("en"));
+
+        // 원본은 그대로
+        assert!(result.data["pt-BR"]["common"].get("bye").is_none());
+    }
+}
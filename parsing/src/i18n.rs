@@ -0,0 +1,129 @@
+//! react-i18next 리소스 번들 생성
+//!
+//! `ParseResult`를 `i18n.init({ resources })`에 바로 넘길 수 있는 형태로 변환합니다:
+//! `{ "en": { "translation": { ... } }, "ko": { "translation": { ... } } }`
+//!
+//! 복수형 키는 i18next v4 (`_one` / `_other`) 형식으로 맞춥니다.
+//! - `items_plural` -> `items_other`
+//! - `items_plural`이 있을 때의 `items` -> `items_one`
+
+use crate::transform::{get_plural_base_key, get_plural_suffix, is_plural_key};
+use crate::types::ParseResult;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// i18next 기본 namespace
+pub const DEFAULT_NAMESPACE: &str = "translation";
+
+/// i18next v3 복수형 접미사 (v4에서는 `_other`)
+const LEGACY_PLURAL_SUFFIX: &str = "plural";
+
+/// ParseResult를 i18next resources 객체로 변환
+pub fn to_resources(result: &ParseResult) -> Value {
+    let mut resources = Map::new();
+
+    for lang in &result.languages {
+        let mut namespace = Map::new();
+        if let Some(data) = result.data.get(lang) {
+            for (k, v) in data {
+                namespace.insert(k.clone(), v.clone());
+            }
+        }
+
+        let mut bundle = Map::new();
+        bundle.insert(
+            DEFAULT_NAMESPACE.to_string(),
+            Value::Object(group_plural_keys(namespace)),
+        );
+        resources.insert(lang.clone(), Value::Object(bundle));
+    }
+
+    Value::Object(resources)
+}
+
+/// 한 object 레벨의 복수형 키를 v4 형식으로 정리 (하위 object는 재귀 처리)
+fn group_plural_keys(map: Map<String, Value>) -> Map<String, Value> {
+    // `_plural` 형식을 쓰는 기본 키 목록
+    let legacy_bases: HashSet<String> = map
+        .keys()
+        .filter(|k| get_plural_suffix(k).as_deref() == Some(LEGACY_PLURAL_SUFFIX))
+        .filter_map(|k| get_plural_base_key(k))
+        .collect();
+
+    let mut grouped = Map::new();
+    for (key, value) in &map {
+        let value = match value {
+            Value::Object(inner) => Value::Object(group_plural_keys(inner.clone())),
+            other => other.clone(),
+        };
+
+        let target = if get_plural_suffix(key).as_deref() == Some(LEGACY_PLURAL_SUFFIX) {
+            // `items_plural` -> `items_other`
+            let base = get_plural_base_key(key).unwrap_or_default();
+            format!("{base}_other")
+        } else if !is_plural_key(key) && legacy_bases.contains(key) {
+            // `items` (단수형) -> `items_one`
+            format!("{key}_one")
+        } else {
+            key.clone()
+        };
+
+        // 이미 v4 키가 명시되어 있으면 그쪽을 우선
+        if target != *key && map.contains_key(&target) {
+            continue;
+        }
+        grouped.insert(target, value);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::csv::parse;
+    use crate::types::ParseOptions;
+
+    #[test]
+    fn test_resources_per_language() {
+        let csv_str = "key,en,ko\ncommon.hello,Hello,안녕\ncommon.bye,Bye,잘가";
+        let result = parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let resources = to_resources(&result);
+        assert_eq!(
+            resources["en"][DEFAULT_NAMESPACE]["common"]["hello"],
+            "Hello"
+        );
+        assert_eq!(resources["ko"][DEFAULT_NAMESPACE]["common"]["bye"], "잘가");
+    }
+
+    #[test]
+    fn test_legacy_plural_keys_grouped() {
+        let csv_str = "key,en\ncart.item,{{count}} item\ncart.item_plural,{{count}} items\ncart.title,Cart";
+        let result = parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let resources = to_resources(&result);
+        let cart = &resources["en"][DEFAULT_NAMESPACE]["cart"];
+        assert_eq!(cart["item_one"], "{{count}} item");
+        assert_eq!(cart["item_other"], "{{count}} items");
+        assert_eq!(cart["title"], "Cart");
+        assert!(cart.get("item").is_none());
+        assert!(cart.get("item_plural").is_none());
+    }
+
+    #[test]
+    fn test_v4_plural_keys_kept() {
+        let csv_str = "key,en\nitems_one,one\nitems_other,other\nitems_plural,legacy";
+        let options = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
+        let result = parse(csv_str.as_bytes(), &options).unwrap();
+
+        let resources = to_resources(&result);
+        let ns = &resources["en"][DEFAULT_NAMESPACE];
+        assert_eq!(ns["items_one"], "one");
+        assert_eq!(ns["items_other"], "other");
+        assert!(ns.get("items_plural").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::ParseError;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -48,6 +49,16 @@ pub fn normalize_lang_code(code: &str) -> String {
     }
 }
 
+/// 지역 코드를 뺀 기본 언어 코드
+/// 예: "pt-BR" -> "pt", "zh_tw" -> "zh", "KO" -> "ko"
+pub fn base_lang_code(code: &str) -> String {
+    let normalized = normalize_lang_code(code);
+    match normalized.split_once('-') {
+        Some((lang, _)) => lang.to_string(),
+        None => normalized,
+    }
+}
+
 /// 알 수 없는 언어 코드에 대해 경고 출력
 #[cfg(target_arch = "wasm32")]
 pub fn warn_unknown_lang_code(code: &str) {
@@ -73,6 +84,25 @@ pub fn warn_unknown_lang_code(code: &str) {
     );
 }
 
+/// 파싱 경고 출력 (`ParseOptions::log_warnings`가 켜진 경우에만 사용)
+#[cfg(target_arch = "wasm32")]
+pub fn log_warning(warning: &ParseError) {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = console)]
+        fn warn(s: &str);
+    }
+
+    warn(&format!("[LocalizeKit] {}", warning));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_warning(warning: &ParseError) {
+    eprintln!("[LocalizeKit] Warning: {}", warning);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,5 +139,12 @@ mod tests {
         assert_eq!(normalize_lang_code("en-us"), "en-US");
         assert_eq!(normalize_lang_code("ZH-cn"), "zh-CN");
     }
+
+    #[test]
+    fn test_base_lang_code() {
+        assert_eq!(base_lang_code("pt-BR"), "pt");
+        assert_eq!(base_lang_code("zh_tw"), "zh");
+        assert_eq!(base_lang_code("KO"), "ko");
+    }
 }
 
@@ -1,26 +1,34 @@
+pub mod diff;
 pub mod error;
+pub mod i18n;
 pub mod lang_codes;
+pub mod merge;
 pub mod parser;
+pub mod pseudo;
 pub mod export;
+pub mod fallback;
 pub mod transform;
 pub mod types;
+pub mod validate;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
 
 #[cfg(test)]
 mod bench;
 
 use wasm_bindgen::prelude::*;
 
-use crate::error::{ErrorKind, ParseError};
-use crate::types::{OutputFormat, ParseOptions, ParseResult};
+use crate::error::ParseError;
+use crate::types::{KeyOrder, OutputFormat, ParseOptions, ParseResult, SheetSelection};
+use std::collections::BTreeMap;
 
-fn serialize_result(result: &ParseResult, format: OutputFormat) -> std::result::Result<String, ParseError> {
+/// ParseResult를 지정한 출력 포맷 문자열로 직렬화
+pub fn serialize_result(result: &ParseResult, format: OutputFormat) -> std::result::Result<String, ParseError> {
     match format {
         OutputFormat::Json => serde_json::to_string(result).map_err(ParseError::json_serialize_error),
         OutputFormat::Yaml => serde_yaml::to_string(result).map_err(ParseError::yaml_serialize_error),
-        OutputFormat::I18n => Err(ParseError::new(
-            ErrorKind::Unknown,
-            "i18n format is not implemented yet",
-        )),
+        OutputFormat::I18n => serde_json::to_string(&i18n::to_resources(result))
+            .map_err(ParseError::json_serialize_error),
     }
 }
 
@@ -52,6 +60,7 @@ pub fn parse_csv(
         nested,
         output_format: OutputFormat::Json,
         process_escapes,
+        ..Default::default()
     };
 
     match parser::csv::parse(data, &options) {
@@ -83,6 +92,7 @@ pub fn parse_excel(
         nested,
         output_format: OutputFormat::Json,
         process_escapes,
+        ..Default::default()
     };
 
     match parser::excel::parse(data, &options) {
@@ -92,6 +102,115 @@ pub fn parse_excel(
     }
 }
 
+/// Excel 시트 선택 파싱 - WASM 바인딩
+///
+/// # Arguments
+/// * `sheet` - 시트 이름 또는 번호 (0부터), 빈 문자열이면 첫 번째 시트,
+///   `"*"`이면 모든 시트를 파싱하고 시트 이름을 최상위 네임스페이스로 사용
+#[wasm_bindgen]
+pub fn parse_excel_sheet(
+    data: &[u8],
+    sheet: &str,
+    separator: &str,
+    nested: bool,
+    process_escapes: bool,
+) -> Result<String, JsValue> {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes,
+        ..Default::default()
+    };
+
+    match parser::excel::parse_sheet(data, &options, &SheetSelection::from_str_lossy(sheet)) {
+        Ok(result) => serialize_result(&result, OutputFormat::Json)
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// Excel 시트 이름 목록 - WASM 바인딩
+#[wasm_bindgen]
+pub fn get_excel_sheets(data: &[u8]) -> Result<String, JsValue> {
+    match parser::excel::list_sheets(data) {
+        Ok(sheets) => serde_json::to_string(&sheets).map_err(|e| {
+            let err = error::ParseError::json_serialize_error(e);
+            JsValue::from_str(&err.to_json())
+        }),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// 옵션 객체로 CSV 파싱 - WASM 바인딩
+///
+/// # Arguments
+/// * `options_json` - `ParseOptions` JSON (빠진 필드는 기본값)
+///   예: `{"separator": ".", "duplicate_keys": "error"}`
+///
+/// # Returns
+/// `output_format`에 맞게 직렬화된 결과 또는 에러 JSON
+#[wasm_bindgen]
+pub fn parse_csv_with_options(data: &[u8], options_json: &str) -> Result<String, JsValue> {
+    let options = parse_options_from_json(options_json)?;
+
+    match parser::csv::parse(data, &options) {
+        Ok(result) => serialize_result(&result, options.output_format.clone())
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// 옵션 객체로 Excel 파싱 - WASM 바인딩 (`sheet`는 `parse_excel_sheet`와 같음)
+#[wasm_bindgen]
+pub fn parse_excel_with_options(data: &[u8], sheet: &str, options_json: &str) -> Result<String, JsValue> {
+    let options = parse_options_from_json(options_json)?;
+
+    match parser::excel::parse_sheet(data, &options, &SheetSelection::from_str_lossy(sheet)) {
+        Ok(result) => serialize_result(&result, options.output_format.clone())
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// CSV 진단 파싱 - WASM 바인딩
+///
+/// 첫 에러에서 멈추지 않고 모든 문제를 수집합니다. 항상 성공하며,
+/// `{ "result": {...} | null, "errors": [...], "warnings": [...] }`를 반환합니다.
+/// (`errors`/`warnings`의 각 항목은 에러 JSON과 같은 형식)
+#[wasm_bindgen]
+pub fn parse_csv_report(data: &[u8], separator: &str, nested: bool, process_escapes: bool) -> String {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes,
+        ..Default::default()
+    };
+
+    parser::csv::parse_report(data, &options).to_json()
+}
+
+/// Excel 진단 파싱 - WASM 바인딩 (`sheet`는 `parse_excel_sheet`와 같음)
+#[wasm_bindgen]
+pub fn parse_excel_report(
+    data: &[u8],
+    sheet: &str,
+    separator: &str,
+    nested: bool,
+    process_escapes: bool,
+) -> String {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes,
+        ..Default::default()
+    };
+
+    parser::excel::parse_report(data, &options, &SheetSelection::from_str_lossy(sheet)).to_json()
+}
+
 /// CSV 헤더만 파싱하여 언어 목록 반환 - WASM 바인딩
 #[wasm_bindgen]
 pub fn get_csv_languages(data: &[u8]) -> Result<String, JsValue> {
@@ -116,6 +235,18 @@ pub fn get_excel_languages(data: &[u8]) -> Result<String, JsValue> {
     }
 }
 
+/// 선택한 Excel 시트의 언어 목록 반환 - WASM 바인딩 (`sheet`는 `parse_excel_sheet`와 같음)
+#[wasm_bindgen]
+pub fn get_excel_sheet_languages(data: &[u8], sheet: &str) -> Result<String, JsValue> {
+    match parser::excel::parse_sheet_header_only(data, &SheetSelection::from_str_lossy(sheet)) {
+        Ok(languages) => serde_json::to_string(&languages).map_err(|e| {
+            let err = error::ParseError::json_serialize_error(e);
+            JsValue::from_str(&err.to_json())
+        }),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
 /// CSV 파싱 - YAML 출력
 #[wasm_bindgen]
 pub fn parse_csv_yaml(
@@ -129,6 +260,7 @@ pub fn parse_csv_yaml(
         nested,
         output_format: OutputFormat::Yaml,
         process_escapes,
+        ..Default::default()
     };
 
     match parser::csv::parse(data, &options) {
@@ -151,6 +283,7 @@ pub fn parse_excel_yaml(
         nested,
         output_format: OutputFormat::Yaml,
         process_escapes,
+        ..Default::default()
     };
 
     match parser::excel::parse(data, &options) {
@@ -160,17 +293,357 @@ pub fn parse_excel_yaml(
     }
 }
 
+/// CSV 파싱 - react-i18next resources 출력
+#[wasm_bindgen]
+pub fn parse_csv_i18n(
+    data: &[u8],
+    separator: &str,
+    nested: bool,
+    process_escapes: bool,
+) -> Result<String, JsValue> {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::I18n,
+        process_escapes,
+        ..Default::default()
+    };
+
+    match parser::csv::parse(data, &options) {
+        Ok(result) => serialize_result(&result, OutputFormat::I18n)
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// Excel 파싱 - react-i18next resources 출력
+#[wasm_bindgen]
+pub fn parse_excel_i18n(
+    data: &[u8],
+    separator: &str,
+    nested: bool,
+    process_escapes: bool,
+) -> Result<String, JsValue> {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::I18n,
+        process_escapes,
+        ..Default::default()
+    };
+
+    match parser::excel::parse(data, &options) {
+        Ok(result) => serialize_result(&result, OutputFormat::I18n)
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// 빈 문자열 인자를 None으로 변환 (WASM에서는 Option 대신 빈 문자열 사용)
+fn non_empty(s: &str) -> Option<&str> {
+    let s = s.trim();
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+/// "alphabetical", "source", "natural" (없거나 빈 문자열이면 알파벳 순)
+fn key_order_from_str(order: Option<String>) -> Result<KeyOrder, JsValue> {
+    let Some(name) = order.as_deref().and_then(non_empty) else {
+        return Ok(KeyOrder::default());
+    };
+    KeyOrder::parse(name).ok_or_else(|| {
+        let err = ParseError::new(error::ErrorKind::Unknown, format!("Unknown key order '{}'", name))
+            .with_suggestion("Use 'alphabetical', 'source' or 'natural'");
+        JsValue::from_str(&err.to_json())
+    })
+}
+
+/// 직렬화된 ParseResult(JSON)를 역직렬화
+fn parse_result_from_json(result_json: &str) -> Result<ParseResult, JsValue> {
+    serde_json::from_str(result_json).map_err(|e| {
+        let err = ParseError::json_parse_error("result", e);
+        JsValue::from_str(&err.to_json())
+    })
+}
+
+/// `ParseOptions` JSON을 역직렬화 (빈 문자열이면 기본값)
+fn parse_options_from_json(options_json: &str) -> Result<ParseOptions, JsValue> {
+    let Some(options_json) = non_empty(options_json) else {
+        return Ok(ParseOptions::default());
+    };
+    serde_json::from_str(options_json).map_err(|e| {
+        let err = ParseError::json_parse_error("options", e);
+        JsValue::from_str(&err.to_json())
+    })
+}
+
+/// Gettext PO 파싱 - WASM 바인딩
+///
+/// # Arguments
+/// * `data` - PO 파일 바이트 데이터
+/// * `language` - 번역 언어 (빈 문자열이면 PO 헤더의 `Language` 사용)
+/// * `source_language` - msgid를 원문으로 취급할 언어 (빈 문자열이면 사용 안 함)
+/// * `separator` - 키 구분자 (".", "/", "-")
+/// * `nested` - nested object로 변환 여부
+///
+/// # Returns
+/// JSON string with parsed data or error details
+#[wasm_bindgen]
+pub fn parse_po(
+    data: &[u8],
+    language: &str,
+    source_language: &str,
+    separator: &str,
+    nested: bool,
+) -> Result<String, JsValue> {
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes: false,
+        ..Default::default()
+    };
+
+    match parser::po::parse(data, non_empty(language), non_empty(source_language), &options) {
+        Ok(result) => serialize_result(&result, OutputFormat::Json)
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// ParseResult(JSON) -> 언어별 PO 파일
+/// 반환값: `{ "en": "<po text>", "ko": "<po text>" }`
+#[wasm_bindgen]
+pub fn export_po(result_json: &str, source_language: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let files = parser::po::to_po_files(&result, non_empty(source_language), separator);
+    serde_json::to_string(&files)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// ParseResult(JSON) -> POT 템플릿
+#[wasm_bindgen]
+pub fn export_pot(result_json: &str, source_language: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    Ok(parser::po::to_pot(&result, non_empty(source_language), separator))
+}
+
+/// ParseResult(JSON) -> 대상 언어별 XLIFF 파일
+///
+/// # Arguments
+/// * `source_language` - 원문 언어
+/// * `separator` - 키 구분자 (nested 데이터를 flat 키로 변환)
+/// * `version` - "1.2" 또는 "2.0"
+///
+/// # Returns
+/// `{ "ko": "<xliff ...>", "ja": "<xliff ...>" }`
+#[wasm_bindgen]
+pub fn export_xliff(
+    result_json: &str,
+    source_language: &str,
+    separator: &str,
+    version: &str,
+) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let options = parser::xliff::XliffExportOptions {
+        version: parser::xliff::XliffVersion::from_str_lossy(version),
+        source_language: lang_codes::normalize_lang_code(source_language.trim()),
+        separator: separator.to_string(),
+        ..Default::default()
+    };
+
+    let files = parser::xliff::to_xliff_files(&result, &options);
+    serde_json::to_string(&files)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// 번역된 XLIFF를 ParseResult(JSON)에 병합
+///
+/// # Returns
+/// `{ "result": ParseResult, "targetLanguage": "ko", "notes": { "key": ["..."] } }`
+#[wasm_bindgen]
+pub fn merge_xliff(
+    result_json: &str,
+    data: &[u8],
+    separator: &str,
+    nested: bool,
+) -> Result<String, JsValue> {
+    let mut result = parse_result_from_json(result_json)?;
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes: false,
+        ..Default::default()
+    };
+
+    let doc = parser::xliff::merge(&mut result, data, &options)
+        .map_err(|e| JsValue::from_str(&e.to_json()))?;
+
+    Ok(serde_json::json!({
+        "result": result,
+        "targetLanguage": doc.target_language,
+        "notes": doc.notes(),
+    })
+    .to_string())
+}
+
+/// ParseResult(JSON) -> 언어별 Android `strings.xml`
+///
+/// # Arguments
+/// * `separator` - 키 구분자 (리소스 이름에서는 `_`로 변환)
+/// * `default_language` - `values/` 디렉토리에 넣을 언어 (빈 문자열이면 모든 언어가 `values-xx/`)
+///
+/// # Returns
+/// `{ "values/strings.xml": "<resources>...", "values-ko/strings.xml": "..." }`
+#[wasm_bindgen]
+pub fn export_android(result_json: &str, separator: &str, default_language: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let files = parser::android::to_resource_files(&result, separator, non_empty(default_language))
+        .map_err(|e| JsValue::from_str(&e.to_json()))?;
+    serde_json::to_string(&files)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// Android `values*/strings.xml` 파일들 -> ParseResult(JSON)
+///
+/// # Arguments
+/// * `files_json` - `[{ "path": "res/values-ko/strings.xml", "content": "..." }, ...]`
+/// * `default_language` - `values/` 디렉토리의 언어
+/// * `reference_json` - 원래 키 복원용 ParseResult(JSON), 빈 문자열이면 리소스 이름을 키로 사용
+#[wasm_bindgen]
+pub fn parse_android(
+    files_json: &str,
+    default_language: &str,
+    reference_json: &str,
+    separator: &str,
+    nested: bool,
+) -> Result<String, JsValue> {
+    let files: Vec<parser::android::AndroidResourceInput> = serde_json::from_str(files_json)
+        .map_err(|e| JsValue::from_str(&ParseError::json_parse_error("files", e).to_json()))?;
+    let reference = match non_empty(reference_json) {
+        Some(json) => Some(parse_result_from_json(json)?),
+        None => None,
+    };
+    let options = ParseOptions {
+        separator: separator.to_string(),
+        nested,
+        output_format: OutputFormat::Json,
+        process_escapes: false,
+        ..Default::default()
+    };
+
+    match parser::android::parse(&files, default_language.trim(), reference.as_ref(), &options) {
+        Ok(result) => serialize_result(&result, OutputFormat::Json)
+            .map_err(|e| JsValue::from_str(&e.to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// ParseResult(JSON) -> 언어별 iOS `Localizable.strings` / `Localizable.stringsdict`
+///
+/// # Returns
+/// `{ "en.lproj/Localizable.strings": "...", "en.lproj/Localizable.stringsdict": "..." }`
+#[wasm_bindgen]
+pub fn export_ios_strings(result_json: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let files = parser::ios::to_lproj_files(&result, separator);
+    serde_json::to_string(&files)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// ParseResult(JSON) -> `Localizable.xcstrings` (String Catalog)
+#[wasm_bindgen]
+pub fn export_xcstrings(result_json: &str, source_language: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    parser::ios::to_xcstrings(&result, &lang_codes::normalize_lang_code(source_language.trim()), separator)
+        .map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// `Localizable.xcstrings` -> `{ header, rows }` 표 (`jsons_to_table`과 같은 형식, 마지막 열은 `description`)
+///
+/// # Arguments
+/// * `reference_json` - 변수 이름 복원용 ParseResult(JSON), 빈 문자열이면 `%1$@` -> `{{arg1}}`
+#[wasm_bindgen]
+pub fn xcstrings_to_table(data: &str, reference_json: &str, separator: &str) -> Result<String, JsValue> {
+    let reference = match non_empty(reference_json) {
+        Some(json) => Some(parse_result_from_json(json)?),
+        None => None,
+    };
+
+    match parser::ios::parse_xcstrings(data, reference.as_ref(), separator) {
+        Ok(table) => serde_json::to_string(&table)
+            .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
+/// ParseResult(JSON) -> 언어별 Flutter ARB 파일
+///
+/// # Arguments
+/// * `prefix` - 파일 이름 접두사 (빈 문자열이면 "app")
+///
+/// # Returns
+/// `{ "app_en.arb": "{...}", "app_ko.arb": "{...}" }`
+#[wasm_bindgen]
+pub fn export_arb(result_json: &str, separator: &str, prefix: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let files = parser::arb::to_arb_files(&result, separator, non_empty(prefix).unwrap_or("app"))
+        .map_err(|e| JsValue::from_str(&e.to_json()))?;
+    serde_json::to_string(&files)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// Flutter ARB 파일들 -> `{ header, rows }` 표 (마지막 열은 `description`)
+///
+/// # Arguments
+/// * `inputs_json` - `[{ "language": "en", "content": "{...arb...}" }, ...]` (`language`는 생략 시 `@@locale`)
+/// * `reference_json` - 원래 키 복원용 ParseResult(JSON), 빈 문자열이면 ARB 메시지 이름을 키로 사용
+#[wasm_bindgen]
+pub fn arb_to_table(inputs_json: &str, reference_json: &str, separator: &str) -> Result<String, JsValue> {
+    let inputs: Vec<parser::arb::ArbInput> = serde_json::from_str(inputs_json)
+        .map_err(|e| JsValue::from_str(&ParseError::json_parse_error("inputs", e).to_json()))?;
+    let reference = match non_empty(reference_json) {
+        Some(json) => Some(parse_result_from_json(json)?),
+        None => None,
+    };
+
+    match parser::arb::parse_arb_files(&inputs, reference.as_ref(), separator) {
+        Ok(table) => serde_json::to_string(&table)
+            .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
 /// Excel -> CSV 변환 (첫 번째 시트)
 #[wasm_bindgen]
 pub fn excel_to_csv(data: &[u8]) -> Result<String, JsValue> {
     parser::excel::to_csv(data).map_err(|e| JsValue::from_str(&e.to_json()))
 }
 
+/// 선택한 Excel 시트 -> CSV 변환 (`sheet`: 시트 이름 또는 번호, 빈 문자열이면 첫 번째 시트)
+#[wasm_bindgen]
+pub fn excel_sheet_to_csv(data: &[u8], sheet: &str) -> Result<String, JsValue> {
+    parser::excel::sheet_to_csv(data, &SheetSelection::from_str_lossy(sheet))
+        .map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
 /// Rewrite key separator in CSV text (header is kept as-is).
 /// Replaces '.', '/', '-' in the first column (key) with `target_sep`.
 #[wasm_bindgen]
-pub fn rewrite_csv_key_separator(csv_text: &str, target_sep: &str) -> String {
-    transform::rewrite_key_separator_in_csv(csv_text, target_sep)
+pub fn rewrite_csv_key_separator(csv_text: &str, target_sep: &str) -> Result<String, JsValue> {
+    transform::rewrite_key_separator_in_csv(csv_text, target_sep).map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// `jsons_to_*` 바인딩의 입력 배열 파싱
+fn parse_lang_json_inputs(inputs_json: &str) -> Result<Vec<export::LangJsonInput>, JsValue> {
+    serde_json::from_str(inputs_json).map_err(|e| {
+        let err = ParseError::json_parse_error("inputs", e);
+        JsValue::from_str(&err.to_json())
+    })
 }
 
 /// Merge multiple JSON locale files into a CSV string.
@@ -179,26 +652,326 @@ pub fn rewrite_csv_key_separator(csv_text: &str, target_sep: &str) -> String {
 /// Missing translations are filled with an empty string.
 #[wasm_bindgen]
 pub fn jsons_to_csv(inputs_json: &str, separator: &str) -> Result<String, JsValue> {
-    let inputs: Vec<export::LangJsonInput> = serde_json::from_str(inputs_json).map_err(|e| {
-        let err = ParseError::json_parse_error("inputs", e);
-        JsValue::from_str(&err.to_json())
-    })?;
-
+    let inputs = parse_lang_json_inputs(inputs_json)?;
     export::merge_jsons_to_csv(&inputs, separator).map_err(|e| JsValue::from_str(&e.to_json()))
 }
 
+/// Same as `jsons_to_csv`, with a key order.
+/// `order` is "alphabetical" (default), "source" (order of the JSON files) or "natural".
+#[wasm_bindgen]
+pub fn jsons_to_csv_with_order(
+    inputs_json: &str,
+    separator: &str,
+    order: Option<String>,
+) -> Result<String, JsValue> {
+    let inputs = parse_lang_json_inputs(inputs_json)?;
+    export::merge_jsons_to_csv_with_order(&inputs, separator, key_order_from_str(order)?)
+        .map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// `TableData` 결과를 JSON 문자열로 변환
+fn table_to_json(table: Result<export::TableData, ParseError>) -> Result<String, JsValue> {
+    match table {
+        Ok(table) => serde_json::to_string(&table)
+            .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json())),
+        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    }
+}
+
 /// Merge multiple JSON locale files into a table (header + rows) serialized as JSON.
 /// Helpful for generating Excel on the frontend without pulling in heavy WASM deps.
 #[wasm_bindgen]
 pub fn jsons_to_table(inputs_json: &str, separator: &str) -> Result<String, JsValue> {
-    let inputs: Vec<export::LangJsonInput> = serde_json::from_str(inputs_json).map_err(|e| {
-        let err = ParseError::json_parse_error("inputs", e);
+    let inputs = parse_lang_json_inputs(inputs_json)?;
+    table_to_json(export::merge_jsons_to_table(&inputs, separator))
+}
+
+/// Same as `jsons_to_table`, with a key order (see `jsons_to_csv_with_order`).
+#[wasm_bindgen]
+pub fn jsons_to_table_with_order(
+    inputs_json: &str,
+    separator: &str,
+    order: Option<String>,
+) -> Result<String, JsValue> {
+    let inputs = parse_lang_json_inputs(inputs_json)?;
+    table_to_json(export::merge_jsons_to_table_with_order(&inputs, separator, key_order_from_str(order)?))
+}
+
+/// Merge multiple JSON locale files into an xlsx workbook (same inputs as `jsons_to_csv`).
+/// The header row is frozen and bold, and missing translations are highlighted.
+/// Returns the file bytes (`Uint8Array` in JS).
+#[cfg(feature = "xlsx")]
+#[wasm_bindgen]
+pub fn jsons_to_xlsx(inputs_json: &str, separator: &str) -> Result<Vec<u8>, JsValue> {
+    let inputs = parse_lang_json_inputs(inputs_json)?;
+    export::merge_jsons_to_xlsx(&inputs, separator).map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// Same as `jsons_to_xlsx`, with a key order (see `jsons_to_csv_with_order`).
+#[cfg(feature = "xlsx")]
+#[wasm_bindgen]
+pub fn jsons_to_xlsx_with_order(
+    inputs_json: &str,
+    separator: &str,
+    order: Option<String>,
+) -> Result<Vec<u8>, JsValue> {
+    let inputs = parse_lang_json_inputs(inputs_json)?;
+    export::merge_jsons_to_xlsx_with_order(&inputs, separator, key_order_from_str(order)?)
+        .map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// 플레이스홀더 일관성 검사 - WASM 바인딩
+///
+/// # Arguments
+/// * `result_json` - 직렬화된 ParseResult
+/// * `source_language` - 기준 언어 (빈 문자열이면 첫 번째 언어)
+/// * `separator` - nested 키를 펼칠 때 사용할 구분자
+///
+/// # Returns
+/// 에러 JSON과 같은 형식의 항목 배열 (`kind`: `PLACEHOLDER_MISSING` 등, `location`에 key와 언어 컬럼)
+#[wasm_bindgen]
+pub fn check_placeholders(result_json: &str, source_language: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let issues = validate::check_placeholders(&result, non_empty(source_language), separator);
+    serde_json::to_string(&issues)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// HTML/마크업 태그 검사 - WASM 바인딩 (인자와 반환 형식은 `check_placeholders`와 같음)
+///
+/// 문자열마다 태그 짝(`TAG_UNBALANCED`)을 확인하고, 원문과 비교해
+/// 빠지거나(`TAG_MISSING`) 추가된(`TAG_EXTRA`) 태그를 보고합니다.
+#[wasm_bindgen]
+pub fn check_tags(result_json: &str, source_language: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let issues = validate::check_tags(&result, non_empty(source_language), separator);
+    serde_json::to_string(&issues)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// 언어별 번역 현황 - WASM 바인딩
+///
+/// # Arguments
+/// * `result_json` - 직렬화된 ParseResult
+/// * `reference_language` - 기준 언어 (빈 문자열이면 첫 번째 언어)
+/// * `separator` - nested 키를 펼칠 때 사용할 구분자
+/// * `strict` - 누락을 에러로 취급하여 `errors`에 `MISSING_TRANSLATION` 항목 추가
+///
+/// # Returns
+/// `{ "reference_language", "total_keys", "languages": [{ "language", "translated",
+/// "missing_count", "missing", "percent" }], "errors"?: [...] }`
+#[wasm_bindgen]
+pub fn get_coverage_report(
+    result_json: &str,
+    reference_language: &str,
+    separator: &str,
+    strict: bool,
+) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let report = validate::coverage_report(&result, non_empty(reference_language), separator);
+
+    let mut value = serde_json::to_value(&report)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))?;
+    if strict {
+        value["errors"] = report.missing_errors().iter().map(ParseError::to_json_value).collect();
+    }
+    Ok(value.to_string())
+}
+
+/// 최대 길이 검사 - WASM 바인딩
+///
+/// # Arguments
+/// * `result_json` - 직렬화된 ParseResult (`metadata`의 `max_length` 사용)
+/// * `limits_json` - 추가 최대 길이 `{ "key": 20, ... }` (빈 문자열이면 없음, 같은 키는 이 값이 우선)
+/// * `unit` - "graphemes"(기본, 글자 수) 또는 "display_width"(전각 문자 2칸)
+/// * `separator` - nested 키를 펼칠 때 사용할 구분자
+///
+/// # Returns
+/// `{ "unit", "limited_keys", "languages": [{ "language", "overflows": [{ "key", "max_length", "length" }] }],
+/// "errors": [...] }` (`errors`는 초과마다 `MAX_LENGTH_EXCEEDED` 항목)
+#[wasm_bindgen]
+pub fn check_max_length(result_json: &str, limits_json: &str, unit: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let limits: BTreeMap<String, usize> = match non_empty(limits_json) {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| JsValue::from_str(&ParseError::json_parse_error("limits", e).to_json()))?,
+        None => BTreeMap::new(),
+    };
+    let unit = validate::LengthUnit::parse(unit).ok_or_else(|| {
+        let err = ParseError::new(error::ErrorKind::Unknown, format!("Unknown length unit '{}'", unit))
+            .with_suggestion("Use 'graphemes' or 'display_width'");
         JsValue::from_str(&err.to_json())
     })?;
 
-    match export::merge_jsons_to_table(&inputs, separator) {
-        Ok(table) => serde_json::to_string(&table)
-            .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json())),
-        Err(e) => Err(JsValue::from_str(&e.to_json())),
+    let report = validate::check_max_length(&result, &limits, unit, separator);
+    let mut value = serde_json::to_value(&report)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))?;
+    value["errors"] = report.errors().iter().map(ParseError::to_json_value).collect();
+    Ok(value.to_string())
+}
+
+/// 언어 fallback 채우기 - WASM 바인딩
+///
+/// # Arguments
+/// * `result_json` - 직렬화된 ParseResult
+/// * `options_json` - `{ "default_language": "en", "overrides": { "pt-BR": ["pt", "en"] } }`
+///   (빈 문자열이면 기본 체인: 지역 언어 -> 기본 언어 -> 첫 번째 언어)
+/// * `separator` - `inherited` 키를 만들 때 사용할 구분자
+///
+/// # Returns
+/// `{ "result": ParseResult, "inherited": { "pt-BR": { "common.bye": "pt" } } }`
+#[wasm_bindgen]
+pub fn fill_fallbacks(result_json: &str, options_json: &str, separator: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let options: fallback::FallbackOptions = match non_empty(options_json) {
+        Some(json) => serde_json::from_str(json).map_err(|e| {
+            let err = ParseError::json_parse_error("options", e);
+            JsValue::from_str(&err.to_json())
+        })?,
+        None => Default::default(),
+    };
+
+    let filled = fallback::fill_fallbacks(&result, &options, separator);
+    serde_json::to_string(&filled)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))
+}
+
+/// 의사 번역 언어 추가 - WASM 바인딩
+///
+/// # Arguments
+/// * `result_json` - 직렬화된 ParseResult
+/// * `options_json` - `{ "source_language": "en", "language": "en-XA", "expansion": 0.3,
+///   "brackets": true, "rtl": false }` (빠진 필드는 기본값, 빈 문자열이면 모두 기본값)
+///
+/// # Returns
+/// 의사 번역 언어(기본 `en-XA`, RTL이면 `ar-XB`)가 추가된 ParseResult JSON
+#[wasm_bindgen]
+pub fn pseudo_localize(result_json: &str, options_json: &str) -> Result<String, JsValue> {
+    let result = parse_result_from_json(result_json)?;
+    let options: pseudo::PseudoOptions = match non_empty(options_json) {
+        Some(json) => serde_json::from_str(json).map_err(|e| {
+            let err = ParseError::json_parse_error("options", e);
+            JsValue::from_str(&err.to_json())
+        })?,
+        None => Default::default(),
+    };
+
+    serialize_result(&pseudo::pseudo_localize(&result, &options), OutputFormat::Json)
+        .map_err(|e| JsValue::from_str(&e.to_json()))
+}
+
+/// 두 버전의 번역 데이터 비교 - WASM 바인딩
+///
+/// # Arguments
+/// * `old_data`, `new_data` - 비교할 데이터 (CSV, Excel, 또는 `[{ "language", "content" }]` JSON의 UTF-8 바이트)
+/// * `old_format`, `new_format` - "csv", "excel", "json" (빈 문자열이면 데이터로 판단)
+/// * `separator` - JSON의 nested 키를 이어 붙일 구분자
+///
+/// # Returns
+/// `{ "added_keys", "removed_keys", "added_languages", "removed_languages",
+/// "languages": [{ "language", "added", "removed", "changed" }], "text": "PR 코멘트용 텍스트" }`
+#[wasm_bindgen]
+pub fn diff_locales(
+    old_data: &[u8],
+    old_format: &str,
+    new_data: &[u8],
+    new_format: &str,
+    separator: &str,
+) -> Result<String, JsValue> {
+    let load = |data: &[u8], format: &str| {
+        diff::DiffFormat::resolve(format, data)
+            .and_then(|format| diff::LocaleSnapshot::load(data, format, separator))
+            .map_err(|e| JsValue::from_str(&e.to_json()))
+    };
+    let changes = diff::diff_locales(&load(old_data, old_format)?, &load(new_data, new_format)?);
+
+    let mut value = serde_json::to_value(&changes)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))?;
+    value["text"] = serde_json::Value::String(changes.to_text());
+    Ok(value.to_string())
+}
+
+/// 3-way 병합 - WASM 바인딩
+///
+/// # Arguments
+/// * `base_json` - 공통 원본 ParseResult
+/// * `ours_json`, `theirs_json` - 각자 수정한 ParseResult
+/// * `separator` - 충돌 키와 CSV 키를 만들 때 사용할 구분자
+///
+/// # Returns
+/// `{ "merged": LocaleData, "conflicts": [{ "language", "key", "base", "ours", "theirs" }], "csv": "병합된 CSV" }`
+/// (충돌한 값은 ours 값으로 병합)
+#[wasm_bindgen]
+pub fn merge_locales(base_json: &str, ours_json: &str, theirs_json: &str, separator: &str) -> Result<String, JsValue> {
+    let base = parse_result_from_json(base_json)?;
+    let ours = parse_result_from_json(ours_json)?;
+    let theirs = parse_result_from_json(theirs_json)?;
+
+    let merged = merge::three_way_merge(&base.data, &ours.data, &theirs.data, separator);
+    let mut languages = ours.languages.clone();
+    languages.extend(theirs.languages.iter().filter(|l| !ours.languages.contains(l)).cloned());
+    let csv = merged
+        .to_lang_json_inputs(&languages)
+        .and_then(|inputs| export::merge_jsons_to_csv_with_order(&inputs, separator, KeyOrder::Source))
+        .map_err(|e| JsValue::from_str(&e.to_json()))?;
+
+    let mut value = serde_json::to_value(&merged)
+        .map_err(|e| JsValue::from_str(&ParseError::json_serialize_error(e).to_json()))?;
+    value["csv"] = serde_json::Value::String(csv);
+    Ok(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result() -> ParseResult {
+        let csv_str = "key,en,ko\ncommon.hello,Hello,안녕\ncommon.item,item,항목\ncommon.item_plural,items,항목들";
+        parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_serialize_json() {
+        let out = serialize_result(&sample_result(), OutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["data"]["en"]["common"]["hello"], "Hello");
+        assert_eq!(value["row_count"], 3);
+        assert!(value.get("warnings").is_none());
+
+        let result = parser::csv::parse(b"key,xx\nhello,Hi", &ParseOptions::default()).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&serialize_result(&result, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(value["warnings"][0]["kind"], "UNKNOWN_LANGUAGE_CODE");
+        assert_eq!(value["warnings"][0]["location"]["columnName"], "xx");
+    }
+
+    #[test]
+    fn test_parse_csv_with_options() {
+        let csv = b"key,en\na.b,1\na.b,2";
+        let out = parse_csv_with_options(csv, r#"{"nested": false, "duplicate_keys": "keep_first"}"#).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["data"]["en"]["a.b"], "1");
+
+        let out = parse_csv_with_options(csv, "").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["data"]["en"]["a"]["b"], "2");
+        assert_eq!(value["warnings"][0]["kind"], "DUPLICATE_KEY");
+    }
+
+    #[test]
+    fn test_serialize_yaml() {
+        let out = serialize_result(&sample_result(), OutputFormat::Yaml).unwrap();
+        assert!(out.contains("languages:"));
+        assert!(out.contains("hello: Hello"));
+    }
+
+    #[test]
+    fn test_serialize_i18n() {
+        let out = serialize_result(&sample_result(), OutputFormat::I18n).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["en"]["translation"]["common"]["hello"], "Hello");
+        assert_eq!(value["ko"]["translation"]["common"]["item_one"], "항목");
+        assert_eq!(value["ko"]["translation"]["common"]["item_other"], "항목들");
+        assert!(value.get("languages").is_none());
     }
 }
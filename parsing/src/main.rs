@@ -1,3 +1,657 @@
-fn main() {
-    println!("Hello, world!");
+//! `localizekit` 커맨드라인 도구
+//!
+//! 브라우저 밖(CI 등)에서 WASM과 같은 파서를 사용하기 위한 네이티브 바이너리입니다.
+//!
+//! ```text
+//! localizekit import <file|-> [--out DIR] [--format json|yaml|i18n] [--separator .] [--flat] [--no-escapes] [--sheet NAME|INDEX|*] [--duplicates POLICY] [--leaf-key KEY] [--strict] [--typed] [--order ORDER] [--fallback LANG]
+//! localizekit export <file.json|lang=file.json>... [--out FILE] [--separator .] [--order ORDER] [--typed]
+//! localizekit convert <file.xlsx|-> [--out FILE] [--sheet NAME|INDEX]
+//! localizekit languages <file|-> [--sheet NAME|INDEX|*]
+//! localizekit check <file|-> [--separator .] [--sheet NAME|INDEX|*] [--duplicates POLICY] [--leaf-key KEY] [--strict] [--max-lengths FILE] [--width]
+//! localizekit diff <old> <new> [--out FILE] [--separator .]
+//! localizekit merge <base> <ours> <theirs> [--out FILE] [--separator .] [--sheet NAME|INDEX]
+//! ```
+//!
+//! 종료 코드: 0 = 성공, 1 = 파싱/IO 에러, 2 = 잘못된 사용법
+
+use parsing::diff::{self, DiffFormat, LocaleSnapshot};
+use parsing::error::ParseError;
+use parsing::export::{merge_jsons_to_csv_with_options, merge_jsons_to_csv_with_order, LangJsonInput, MergeOptions};
+use parsing::fallback::{self, FallbackOptions};
+use parsing::i18n::{self, DEFAULT_NAMESPACE};
+use parsing::merge;
+use parsing::parser;
+use parsing::validate::{self, LengthUnit};
+use parsing::types::{DuplicateKeyPolicy, KeyOrder, OutputFormat, ParseOptions, ParseResult, SheetSelection};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: localizekit <command> [options]
+
+Commands:
+  import <file|->        Parse a CSV/Excel sheet into per-language files
+      --out <dir>          Write one file per language into <dir> (default: print to stdout)
+      --format <fmt>       json (default), yaml or i18n
+      --separator <sep>    Key separator: '.', '/' or '-' (default: '.')
+      --flat               Keep keys flat instead of nesting them
+      --no-escapes         Do not convert \\n, \\t, ... into real characters
+      --sheet <sheet>      Excel sheet name or 0-based index (default: first sheet);
+                           '*' parses every sheet, using sheet names as top-level keys
+      --duplicates <policy> Repeated keys: error, first, last or warn (default: warn,
+                           keeps the last row and prints a warning)
+      --leaf-key <key>     When both 'a' and 'a.b' exist, store the value of 'a' under
+                           this key inside 'a' (e.g. '_') instead of failing
+      --strict             Fail on empty cells for keys the first language has
+      --typed              Restore numbers, true/false, null and [...] JSON arrays, and turn
+                           'items.0', 'items.1' keys into arrays (prefix with ' to keep text)
+      --order <order>      Key order in the output: alphabetical (default), source
+                           (row order of the sheet) or natural (item2 before item10)
+      --fallback <lang>    Fill empty cells from the base language (pt-BR -> pt),
+                           then from <lang>
+  export <json>...       Merge JSON locale files into a CSV sheet
+                         Each input is 'lang=path.json' or 'path/<lang>.json'
+      --out <file>         Write CSV to <file> (default: stdout); a '.xlsx' file is
+                           written as a workbook with missing cells highlighted
+      --separator <sep>    Key separator used to flatten nested keys (default: '.')
+      --order <order>      Row order: alphabetical (default), source (order of the JSON
+                           files) or natural
+      --typed              Write null as 'null' and prefix strings that look like numbers,
+                           true/false, null or arrays with ' (for 'import --typed')
+  convert <file|->       Convert one sheet of an Excel workbook to CSV
+      --out <file>         Write CSV to <file> (default: stdout)
+      --sheet <sheet>      Sheet name or 0-based index (default: first sheet)
+  languages <file|->     Print the language columns of a CSV/Excel sheet
+      --sheet <sheet>      Excel sheet name, index or '*' (default: first sheet)
+  check <file|->         Report every problem in a CSV/Excel sheet (exit 1 if any error)
+                         Placeholder and tag mismatches against the first language and
+                         values over their maximum length are warnings
+      --separator <sep>    Expected key separator (default: '.')
+      --sheet <sheet>      Excel sheet name, index or '*' (default: first sheet)
+      --duplicates <policy> Repeated keys: error, first, last or warn (default: warn)
+      --leaf-key <key>     Same as for import
+      --strict             Report empty cells for keys the first language has as errors
+      --max-lengths <file> JSON object of per-key length limits ({\"key\": 20}); overrides
+                           the sheet's #maxLength column. Longer values are warnings
+      --width              Measure lengths in display width (CJK characters count 2)
+                           instead of characters
+  diff <old> <new>       Show added/removed keys and changed values between two versions
+                         Inputs can be CSV, Excel or a JSON array of {language, content}
+      --out <file>         Write the report to <file> (default: stdout)
+      --separator <sep>    Separator used to flatten nested JSON keys (default: '.')
+  merge <base> <ours> <theirs>
+                         Three-way merge of CSV/Excel copies edited in parallel into one CSV
+                         Conflicting cells keep the value from <ours> (exit 1 if any conflict)
+      --out <file>         Write CSV to <file> (default: stdout)
+      --separator <sep>    Key separator (default: '.')
+      --sheet <sheet>      Excel sheet name or 0-based index (default: first sheet)
+
+Use '-' (or omit the file) to read from stdin.";
+
+/// CLI 에러: 사용법 오류와 파싱/IO 오류를 구분 (종료 코드가 다름)
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Parse(ParseError),
+}
+
+impl From<ParseError> for CliError {
+    fn from(err: ParseError) -> Self {
+        CliError::Parse(err)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        CliError::Parse(ParseError::io_error(&err))
+    }
+}
+
+type CliResult<T> = std::result::Result<T, CliError>;
+
+/// 파싱된 커맨드라인 인자
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    command: String,
+    inputs: Vec<String>,
+    out: Option<String>,
+    format: Option<String>,
+    separator: Option<String>,
+    sheet: Option<String>,
+    duplicates: Option<String>,
+    order: Option<String>,
+    leaf_key: Option<String>,
+    flat: bool,
+    strict: bool,
+    typed: bool,
+    max_lengths: Option<String>,
+    width: bool,
+    fallback: Option<String>,
+    no_escapes: bool,
+}
+
+fn parse_args(raw: &[String]) -> CliResult<Args> {
+    let mut iter = raw.iter();
+    let command = iter
+        .next()
+        .ok_or_else(|| CliError::Usage("missing command".to_string()))?
+        .clone();
+
+    let mut args = Args {
+        command,
+        ..Default::default()
+    };
+
+    while let Some(arg) = iter.next() {
+        let mut value_for = |name: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| CliError::Usage(format!("option '{}' requires a value", name)))
+        };
+
+        match arg.as_str() {
+            "--out" | "-o" => args.out = Some(value_for(arg)?),
+            "--format" | "-f" => args.format = Some(value_for(arg)?),
+            "--separator" | "-s" => args.separator = Some(value_for(arg)?),
+            "--sheet" => args.sheet = Some(value_for(arg)?),
+            "--duplicates" => args.duplicates = Some(value_for(arg)?),
+            "--order" => args.order = Some(value_for(arg)?),
+            "--leaf-key" => args.leaf_key = Some(value_for(arg)?),
+            "--flat" => args.flat = true,
+            "--strict" => args.strict = true,
+            "--typed" => args.typed = true,
+            "--max-lengths" => args.max_lengths = Some(value_for(arg)?),
+            "--width" => args.width = true,
+            "--fallback" => args.fallback = Some(value_for(arg)?),
+            "--no-escapes" => args.no_escapes = true,
+            "-" => args.inputs.push(arg.clone()),
+            other if other.starts_with('-') => {
+                return Err(CliError::Usage(format!("unknown option '{}'", other)));
+            }
+            other => args.inputs.push(other.to_string()),
+        }
+    }
+
+    Ok(args)
+}
+
+/// 파일 또는 stdin(`-` / 생략)에서 바이트 읽기
+fn read_input(path: Option<&str>) -> CliResult<Vec<u8>> {
+    match path {
+        None | Some("-") => {
+            let mut buf = Vec::new();
+            io::stdin().read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+        Some(p) => Ok(fs::read(p)?),
+    }
+}
+
+/// 파일 또는 stdout에 쓰기
+fn write_output(path: Option<&str>, content: &str) -> CliResult<()> {
+    match path {
+        None | Some("-") => {
+            let mut stdout = io::stdout().lock();
+            stdout.write_all(content.as_bytes())?;
+            if !content.ends_with('\n') {
+                stdout.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+        Some(p) => Ok(fs::write(p, content)?),
+    }
+}
+
+/// xlsx(zip) / xls(OLE) 시그니처로 Excel 여부 판단
+fn is_excel(data: &[u8]) -> bool {
+    data.starts_with(b"PK\x03\x04") || data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0])
+}
+
+fn single_input(args: &Args) -> CliResult<Option<&str>> {
+    match args.inputs.as_slice() {
+        [] => Ok(None),
+        [one] => Ok(Some(one.as_str())),
+        _ => Err(CliError::Usage(format!(
+            "'{}' takes a single input file",
+            args.command
+        ))),
+    }
+}
+
+fn output_format(args: &Args) -> CliResult<OutputFormat> {
+    match args.format.as_deref() {
+        None | Some("json") => Ok(OutputFormat::Json),
+        Some("yaml") | Some("yml") => Ok(OutputFormat::Yaml),
+        Some("i18n") => Ok(OutputFormat::I18n),
+        Some(other) => Err(CliError::Usage(format!(
+            "unknown format '{}' (expected json, yaml or i18n)",
+            other
+        ))),
+    }
+}
+
+fn duplicate_policy(args: &Args) -> CliResult<DuplicateKeyPolicy> {
+    let value = args.duplicates.as_deref().unwrap_or_default();
+    DuplicateKeyPolicy::parse(value).ok_or_else(|| {
+        CliError::Usage(format!(
+            "unknown duplicate key policy '{}' (expected error, first, last or warn)",
+            value
+        ))
+    })
+}
+
+fn key_order(args: &Args) -> CliResult<KeyOrder> {
+    match args.order.as_deref() {
+        None => Ok(KeyOrder::default()),
+        Some(value) => KeyOrder::parse(value).ok_or_else(|| {
+            CliError::Usage(format!(
+                "unknown key order '{}' (expected alphabetical, source or natural)",
+                value
+            ))
+        }),
+    }
+}
+
+/// `--max-lengths` JSON 파일 (키 -> 최대 길이)
+fn max_lengths(args: &Args) -> CliResult<BTreeMap<String, usize>> {
+    let Some(path) = args.max_lengths.as_deref() else {
+        return Ok(BTreeMap::new());
+    };
+    let data = read_input(Some(path))?;
+    Ok(serde_json::from_slice(&data).map_err(|e| ParseError::json_parse_error(path, e))?)
+}
+
+fn sheet_selection(args: &Args) -> SheetSelection {
+    SheetSelection::from_str_lossy(args.sheet.as_deref().unwrap_or_default())
+}
+
+fn run_import(args: &Args) -> CliResult<()> {
+    let data = read_input(single_input(args)?)?;
+    let format = output_format(args)?;
+    let options = ParseOptions {
+        separator: args.separator.clone().unwrap_or_else(|| ".".to_string()),
+        nested: !args.flat,
+        output_format: format.clone(),
+        process_escapes: !args.no_escapes,
+        duplicate_keys: duplicate_policy(args)?,
+        nested_leaf_key: args.leaf_key.clone(),
+        strict_missing: args.strict,
+        typed_values: args.typed,
+        key_order: key_order(args)?,
+        ..Default::default()
+    };
+
+    let mut result = if is_excel(&data) {
+        parser::excel::parse_sheet(&data, &options, &sheet_selection(args))?
+    } else {
+        parser::csv::parse(&data, &options)?
+    };
+    if let Some(default_language) = args.fallback.as_deref() {
+        let fallback_options = FallbackOptions {
+            default_language: Some(default_language.to_string()),
+            ..Default::default()
+        };
+        result = fallback::fill_fallbacks(&result, &fallback_options, &options.separator).result;
+    }
+    for warning in &result.warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    match args.out.as_deref() {
+        Some(dir) => write_language_files(&result, &format, Path::new(dir)),
+        None => write_output(None, &parsing::serialize_result(&result, format)?),
+    }
+}
+
+/// 언어별 파일 쓰기
+/// - json: `<dir>/<lang>.json`
+/// - yaml: `<dir>/<lang>.yaml`
+/// - i18n: `<dir>/<lang>/translation.json` (i18next-http-backend 기본 경로)
+fn write_language_files(result: &ParseResult, format: &OutputFormat, dir: &Path) -> CliResult<()> {
+    // 헤더의 언어 코드가 그대로 경로가 되므로 `../x` 같은 값은 파일을 쓰기 전에 거부
+    for lang in &result.languages {
+        check_file_name(lang)?;
+    }
+    fs::create_dir_all(dir)?;
+    let resources = i18n::to_resources(result);
+
+    for lang in &result.languages {
+        let data = result.data.get(lang).cloned().unwrap_or_default();
+        let (path, content): (PathBuf, String) = match format {
+            OutputFormat::Json => (
+                dir.join(format!("{}.json", lang)),
+                serde_json::to_string_pretty(&data).map_err(ParseError::json_serialize_error)?,
+            ),
+            OutputFormat::Yaml => (
+                dir.join(format!("{}.yaml", lang)),
+                serde_yaml::to_string(&data).map_err(ParseError::yaml_serialize_error)?,
+            ),
+            OutputFormat::I18n => {
+                let lang_dir = dir.join(lang);
+                fs::create_dir_all(&lang_dir)?;
+                (
+                    lang_dir.join(format!("{}.json", DEFAULT_NAMESPACE)),
+                    serde_json::to_string_pretty(&resources[lang][DEFAULT_NAMESPACE])
+                        .map_err(ParseError::json_serialize_error)?,
+                )
+            }
+        };
+
+        fs::write(&path, content)?;
+        eprintln!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// 언어 코드를 파일/디렉터리 이름으로 쓸 수 있는지 확인 (영문, 숫자, `-`, `_`만 허용)
+fn check_file_name(lang: &str) -> CliResult<()> {
+    if !lang.is_empty() && lang.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Ok(());
+    }
+    Err(CliError::Parse(
+        ParseError::new(
+            parsing::error::ErrorKind::UnknownLanguageCode,
+            format!("Language code '{}' cannot be used as a file name", lang),
+        )
+        .with_suggestion("Use only letters, digits, '-' and '_' in language columns (e.g., 'en', 'pt-BR')"),
+    ))
+}
+
+/// `lang=path.json` 또는 `path/<lang>.json` 형식의 입력을 해석
+fn lang_json_input(spec: &str) -> CliResult<LangJsonInput> {
+    let (language, path) = match spec.split_once('=') {
+        Some((lang, path)) => (lang.to_string(), path),
+        None => {
+            let stem = Path::new(spec)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .ok_or_else(|| {
+                    CliError::Usage(format!("cannot infer language from '{}'", spec))
+                })?;
+            (stem.to_string(), spec)
+        }
+    };
+
+    Ok(LangJsonInput {
+        language,
+        content: fs::read_to_string(path)?,
+    })
+}
+
+fn run_export(args: &Args) -> CliResult<()> {
+    if args.inputs.is_empty() {
+        return Err(CliError::Usage("'export' needs at least one JSON file".to_string()));
+    }
+
+    let inputs = args
+        .inputs
+        .iter()
+        .map(|spec| lang_json_input(spec))
+        .collect::<CliResult<Vec<_>>>()?;
+    let separator = args.separator.as_deref().unwrap_or(".");
+    let options = MergeOptions {
+        order: key_order(args)?,
+        typed_values: args.typed,
+    };
+
+    #[cfg(feature = "xlsx")]
+    if let Some(path) = args.out.as_deref().filter(|p| p.to_ascii_lowercase().ends_with(".xlsx")) {
+        fs::write(path, parsing::export::merge_jsons_to_xlsx_with_options(&inputs, separator, &options)?)?;
+        return Ok(());
+    }
+
+    let csv = merge_jsons_to_csv_with_options(&inputs, separator, &options)?;
+    write_output(args.out.as_deref(), &csv)
+}
+
+fn run_convert(args: &Args) -> CliResult<()> {
+    let data = read_input(single_input(args)?)?;
+    let csv = parser::excel::sheet_to_csv(&data, &sheet_selection(args))?;
+    write_output(args.out.as_deref(), &csv)
+}
+
+fn run_diff(args: &Args) -> CliResult<()> {
+    let [old, new] = args.inputs.as_slice() else {
+        return Err(CliError::Usage("diff expects exactly two inputs: <old> <new>".to_string()));
+    };
+    let separator = args.separator.as_deref().unwrap_or(".");
+    let load = |path: &str| -> CliResult<LocaleSnapshot> {
+        let data = read_input(Some(path))?;
+        Ok(LocaleSnapshot::load(&data, DiffFormat::detect(&data), separator)?)
+    };
+
+    let changes = diff::diff_locales(&load(old)?, &load(new)?);
+    write_output(args.out.as_deref(), &changes.to_text())
+}
+
+fn run_merge(args: &Args) -> CliResult<()> {
+    let [base, ours, theirs] = args.inputs.as_slice() else {
+        return Err(CliError::Usage(
+            "merge expects exactly three inputs: <base> <ours> <theirs>".to_string(),
+        ));
+    };
+    let options = ParseOptions {
+        separator: args.separator.clone().unwrap_or_else(|| ".".to_string()),
+        ..Default::default()
+    };
+    let load = |path: &str| -> CliResult<ParseResult> {
+        let data = read_input(Some(path))?;
+        Ok(if is_excel(&data) {
+            parser::excel::parse_sheet(&data, &options, &sheet_selection(args))?
+        } else {
+            parser::csv::parse(&data, &options)?
+        })
+    };
+    let (base, ours, theirs) = (load(base)?, load(ours)?, load(theirs)?);
+
+    let merged = merge::three_way_merge(&base.data, &ours.data, &theirs.data, &options.separator);
+    let mut languages = ours.languages.clone();
+    languages.extend(theirs.languages.iter().filter(|l| !ours.languages.contains(l)).cloned());
+    let csv = merge_jsons_to_csv_with_order(&merged.to_lang_json_inputs(&languages)?, &options.separator, KeyOrder::Source)?;
+    write_output(args.out.as_deref(), &csv)?;
+
+    let show = |value: &Option<serde_json::Value>| match value {
+        Some(serde_json::Value::String(s)) => format!("{:?}", s),
+        Some(other) => other.to_string(),
+        None => "(none)".to_string(),
+    };
+    for conflict in &merged.conflicts {
+        eprintln!(
+            "conflict: [{}] {}: base {}, ours {}, theirs {}",
+            conflict.language,
+            conflict.key,
+            show(&conflict.base),
+            show(&conflict.ours),
+            show(&conflict.theirs)
+        );
+    }
+    if merged.is_clean() {
+        Ok(())
+    } else {
+        Err(CliError::Parse(ParseError::new(
+            parsing::error::ErrorKind::Unknown,
+            format!("{} conflict(s) found", merged.conflicts.len()),
+        )))
+    }
+}
+
+fn run_languages(args: &Args) -> CliResult<()> {
+    let data = read_input(single_input(args)?)?;
+    let languages = if is_excel(&data) {
+        parser::excel::parse_sheet_header_only(&data, &sheet_selection(args))?
+    } else {
+        parser::csv::parse_header_only(&data)?
+    };
+    write_output(None, &languages.join("\n"))
+}
+
+fn run_check(args: &Args) -> CliResult<()> {
+    let data = read_input(single_input(args)?)?;
+    let limits = max_lengths(args)?;
+    let unit = if args.width { LengthUnit::DisplayWidth } else { LengthUnit::Graphemes };
+    let options = ParseOptions {
+        separator: args.separator.clone().unwrap_or_else(|| ".".to_string()),
+        duplicate_keys: duplicate_policy(args)?,
+        nested_leaf_key: args.leaf_key.clone(),
+        strict_missing: args.strict,
+        ..Default::default()
+    };
+
+    let report = if is_excel(&data) {
+        parser::excel::parse_report(&data, &options, &sheet_selection(args))
+    } else {
+        parser::csv::parse_report(&data, &options)
+    };
+
+    // 파싱에 성공하면 번역 내용도 검사 (첫 번째 언어 기준)
+    let mut warnings = report.warnings.clone();
+    if let Some(result) = &report.result {
+        warnings.extend(validate::check_placeholders(result, None, &options.separator));
+        warnings.extend(validate::check_tags(result, None, &options.separator));
+        warnings.extend(validate::check_max_length(result, &limits, unit, &options.separator).errors());
+    }
+
+    for warning in &warnings {
+        eprintln!("warning: {}", warning);
+    }
+    for error in &report.errors {
+        eprintln!("error: {}", error);
+    }
+
+    match (report.errors.len(), &report.result) {
+        (0, Some(result)) => {
+            let mut lines = vec![format!("ok: {} rows, {} warning(s)", result.row_count, warnings.len())];
+            let coverage = validate::coverage_report(result, None, &options.separator);
+            for lang in &coverage.languages {
+                lines.push(format!("{}: {:.1}% ({} missing)", lang.language, lang.percent, lang.missing_count));
+            }
+            write_output(None, &lines.join("\n"))
+        }
+        (0, None) => write_output(None, &format!("ok: 0 rows, {} warning(s)", warnings.len())),
+        (count, _) => Err(CliError::Parse(ParseError::new(
+            parsing::error::ErrorKind::Unknown,
+            format!("{} error(s) found", count),
+        ))),
+    }
+}
+
+fn run(raw: &[String]) -> CliResult<()> {
+    let args = parse_args(raw)?;
+    match args.command.as_str() {
+        "import" => run_import(&args),
+        "export" => run_export(&args),
+        "convert" => run_convert(&args),
+        "languages" => run_languages(&args),
+        "check" => run_check(&args),
+        "diff" => run_diff(&args),
+        "merge" => run_merge(&args),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(CliError::Usage(format!("unknown command '{}'", other))),
+    }
+}
+
+fn main() -> ExitCode {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&raw) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(msg)) => {
+            eprintln!("error: {}\n\n{}", msg, USAGE);
+            ExitCode::from(2)
+        }
+        Err(CliError::Parse(err)) => {
+            eprintln!("{}", err);
+            ExitCode::from(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse_args(&to_args(&[
+            "import", "sheet.xlsx", "--out", "locales", "--format", "yaml", "--flat", "--sheet", "*",
+        ]))
+        .unwrap();
+
+        assert_eq!(args.command, "import");
+        assert_eq!(args.inputs, vec!["sheet.xlsx"]);
+        assert_eq!(sheet_selection(&args), SheetSelection::AllAsNamespaces);
+        assert_eq!(args.out.as_deref(), Some("locales"));
+        assert_eq!(args.format.as_deref(), Some("yaml"));
+        assert!(args.flat);
+        assert!(!args.no_escapes);
+
+        let args = parse_args(&to_args(&["check", "-", "--max-lengths", "limits.json", "--width"])).unwrap();
+        assert_eq!(args.inputs, vec!["-"]);
+        assert_eq!(args.max_lengths.as_deref(), Some("limits.json"));
+        assert!(args.width);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(matches!(parse_args(&[]), Err(CliError::Usage(_))));
+        assert!(matches!(
+            parse_args(&to_args(&["import", "--out"])),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(&to_args(&["import", "--bogus"])),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_write_language_files_rejects_paths() {
+        let dir = std::env::temp_dir().join(format!("localizekit-test-{}", std::process::id()));
+        for lang in ["../../x", "a/b", "a\\b", ".."] {
+            let result = parser::csv::parse(format!("key,{}\nhello,Hi", lang).as_bytes(), &ParseOptions::default()).unwrap();
+            for format in [OutputFormat::Json, OutputFormat::I18n] {
+                assert!(matches!(write_language_files(&result, &format, &dir), Err(CliError::Parse(_))), "{}", lang);
+            }
+        }
+        assert!(!dir.exists());
+
+        let result = parser::csv::parse(b"key,pt-BR\nhello,Oi", &ParseOptions::default()).unwrap();
+        write_language_files(&result, &OutputFormat::Json, &dir).unwrap();
+        assert!(dir.join("pt-BR.json").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_is_excel() {
+        assert!(is_excel(b"PK\x03\x04rest"));
+        assert!(!is_excel(b"key,en,ko\n"));
+    }
+
+    #[test]
+    fn test_write_language_files() {
+        let csv_str = "key,en,ko\ncommon.hello,Hello,안녕";
+        let result = parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let dir = std::env::temp_dir().join(format!("localizekit-cli-{}", std::process::id()));
+
+        write_language_files(&result, &OutputFormat::Json, &dir).unwrap();
+        let en = fs::read_to_string(dir.join("en.json")).unwrap();
+        assert!(en.contains("\"hello\": \"Hello\""));
+
+        write_language_files(&result, &OutputFormat::I18n, &dir).unwrap();
+        let ko = fs::read_to_string(dir.join("ko").join("translation.json")).unwrap();
+        assert!(ko.contains("안녕"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
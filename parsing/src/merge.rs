@@ -0,0 +1,216 @@
+//! 번역 데이터 3-way 병합
+//!
+//! 같은 원본(base)에서 나온 두 사본(ours, theirs)의 변경을 합칩니다.
+//! 한쪽만 바꾼 값은 자동으로 반영하고, 양쪽이 다르게 바꾼 값은 충돌로 보고합니다.
+
+use crate::error::{ParseError, Result};
+use crate::export::LangJsonInput;
+use crate::transform::merge_key_order;
+use crate::types::LocaleData;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// 양쪽이 다르게 바꾼 값 (`None` = 값 없음/삭제)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeConflict {
+    pub language: String,
+    /// flat 키 (nested 키는 구분자로 이어 붙임)
+    pub key: String,
+    pub base: Option<Value>,
+    pub ours: Option<Value>,
+    pub theirs: Option<Value>,
+}
+
+/// 병합 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeResult {
+    /// 병합된 데이터 (충돌한 값은 ours, ours에서 삭제된 경우 theirs 값을 유지)
+    pub merged: LocaleData,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeResult {
+    /// 충돌 없이 병합되었는지 여부
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// `export::merge_jsons_to_csv`에 넘길 입력으로 변환
+    /// `languages` 순서를 먼저 쓰고, 나머지 언어는 뒤에 붙입니다.
+    pub fn to_lang_json_inputs(&self, languages: &[String]) -> Result<Vec<LangJsonInput>> {
+        let mut order: Vec<&String> = languages.iter().filter(|l| self.merged.contains_key(*l)).collect();
+        order.extend(self.merged.keys().filter(|l| !languages.contains(l)));
+
+        order
+            .into_iter()
+            .map(|lang| {
+                let content = serde_json::to_string(&self.merged[lang]).map_err(ParseError::json_serialize_error)?;
+                Ok(LangJsonInput {
+                    language: lang.clone(),
+                    content,
+                })
+            })
+            .collect()
+    }
+}
+
+/// 3-way 병합
+///
+/// 키마다 (빈 문자열은 값 없음으로 취급)
+/// - ours와 theirs가 같으면 그 값
+/// - 한쪽이 base와 같으면 다른 쪽 값 (추가/수정/삭제 모두 반영)
+/// - 둘 다 object이면 하위 키별로 다시 비교
+/// - 그 외에는 충돌
+pub fn three_way_merge(base: &LocaleData, ours: &LocaleData, theirs: &LocaleData, separator: &str) -> MergeResult {
+    let languages: BTreeSet<&String> = base.keys().chain(ours.keys()).chain(theirs.keys()).collect();
+    let mut merged = LocaleData::new();
+    let mut conflicts = Vec::new();
+
+    for language in languages {
+        let as_object = |data: &LocaleData| data.get(language).map(|m| Value::Object(m.clone()));
+        let mut context = MergeContext {
+            language,
+            separator,
+            conflicts: &mut conflicts,
+        };
+        let value = context.merge_value(
+            "",
+            as_object(base).as_ref(),
+            as_object(ours).as_ref(),
+            as_object(theirs).as_ref(),
+        );
+
+        // 한쪽에만 있던 언어가 삭제된 경우가 아니면 언어는 유지
+        let entries = match value {
+            Some(Value::Object(map)) => map,
+            _ => Map::new(),
+        };
+        if ours.contains_key(language) || theirs.contains_key(language) {
+            merged.insert(language.clone(), entries);
+        }
+    }
+
+    MergeResult { merged, conflicts }
+}
+
+struct MergeContext<'a> {
+    language: &'a str,
+    separator: &'a str,
+    conflicts: &'a mut Vec<MergeConflict>,
+}
+
+impl MergeContext<'_> {
+    fn merge_value(&mut self, path: &str, base: Option<&Value>, ours: Option<&Value>, theirs: Option<&Value>) -> Option<Value> {
+        let (base, ours, theirs) = (present(base), present(ours), present(theirs));
+
+        if ours == theirs {
+            return ours.cloned();
+        }
+        if ours == base {
+            return theirs.cloned();
+        }
+        if theirs == base {
+            return ours.cloned();
+        }
+
+        if let (Some(Value::Object(o)), Some(Value::Object(t))) = (ours, theirs) {
+            let b = match base {
+                Some(Value::Object(b)) => Some(b),
+                _ => None,
+            };
+            // base에만 있는 키는 양쪽 모두 삭제한 것이므로 제외, 순서는 ours 기준
+            let keys = merge_key_order(o.keys(), t.keys());
+
+            let mut map = Map::new();
+            for key in keys {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}{}{}", path, self.separator, key)
+                };
+                let value = self.merge_value(&child_path, b.and_then(|b| b.get(key)), o.get(key), t.get(key));
+                if let Some(value) = value {
+                    map.insert(key.clone(), value);
+                }
+            }
+            return Some(Value::Object(map));
+        }
+
+        self.conflicts.push(MergeConflict {
+            language: self.language.to_string(),
+            key: path.to_string(),
+            base: base.cloned(),
+            ours: ours.cloned(),
+            theirs: theirs.cloned(),
+        });
+        ours.or(theirs).cloned()
+    }
+}
+
+/// 빈 문자열/null은 값이 없는 것으로 취급
+fn present(value: Option<&Value>) -> Option<&Value> {
+    match value {
+        Some(Value::Null) => None,
+        Some(Value::String(s)) if s.is_empty() => None,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::merge_jsons_to_csv_with_order;
+    use crate::parser;
+    use crate::types::{KeyOrder, ParseOptions};
+
+    fn data(csv: &str) -> LocaleData {
+        parser::csv::parse(csv.as_bytes(), &ParseOptions::default()).unwrap().data
+    }
+
+    #[test]
+    fn test_three_way_merge() {
+        let base = data("key,en,ko\ncommon.hello,Hello,안녕\ncommon.bye,Bye,잘가\ncommon.save,Save,저장\nold,Old,옛날");
+        let ours = data("key,en,ko\ncommon.hello,Hello,안녕하세요\ncommon.bye,Bye,잘가\ncommon.save,Save,저장하기\nold,Old,옛날");
+        let theirs = data("key,en,ko\ncommon.hello,Hello,안녕\ncommon.bye,Goodbye,잘 가\ncommon.save,Save,보관\nnew,New,새");
+
+        let result = three_way_merge(&base, &ours, &theirs, ".");
+
+        let ko = &result.merged["ko"];
+        assert_eq!(ko["common"]["hello"], "안녕하세요");
+        assert_eq!(ko["common"]["bye"], "잘 가");
+        assert_eq!(ko["common"]["save"], "저장하기");
+        assert_eq!(ko["new"], "새");
+        assert!(!ko.contains_key("old"));
+        assert_eq!(result.merged["en"]["common"]["bye"], "Goodbye");
+
+        assert_eq!(
+            result.conflicts,
+            vec![MergeConflict {
+                language: "ko".into(),
+                key: "common.save".into(),
+                base: Some("저장".into()),
+                ours: Some("저장하기".into()),
+                theirs: Some("보관".into()),
+            }]
+        );
+
+        let inputs = result.to_lang_json_inputs(&["ko".to_string(), "en".to_string()]).unwrap();
+        let csv = merge_jsons_to_csv_with_order(&inputs, ".", KeyOrder::Source).unwrap();
+        assert!(csv.starts_with("key,ko,en\n"));
+        assert!(csv.contains("common.bye,잘 가,Goodbye\n"));
+    }
+
+    #[test]
+    fn test_three_way_merge_delete_conflict() {
+        let base = data("key,en\na,A\nb,B");
+        let ours = data("key,en\nb,B");
+        let theirs = data("key,en\na,A2\nb,B");
+
+        let result = three_way_merge(&base, &ours, &theirs, ".");
+        assert!(!result.is_clean());
+        assert_eq!(result.conflicts[0].ours, None);
+        // 삭제/수정 충돌은 수정된 값을 유지
+        assert_eq!(result.merged["en"]["a"], "A2");
+    }
+}
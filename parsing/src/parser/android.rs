@@ -0,0 +1,748 @@
+//! Android `res/values*/strings.xml` 가져오기/내보내기
+//!
+//! - 키는 리소스 이름으로 변환 (구분자 및 사용할 수 없는 문자 -> `_`)
+//! - `_one` / `_other` 등 복수형 키는 `<plurals>`로 묶음
+//! - 배열 값과 `items.0`, `items.1` 같은 인덱스 키는 `<string-array>`로 묶음
+//! - `{{name}}`은 `<xliff:g id="name">%1$s</xliff:g>`로 변환 (가져올 때 다시 `{{name}}`으로 복원)
+//! - 값은 텍스트로 취급하므로 HTML 태그는 `&lt;b&gt;`처럼 escape 됨
+
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::export::{flatten_locale, value_to_cell};
+use crate::lang_codes::normalize_lang_code;
+use crate::parser::{
+    build_lang_map, placeholder_order, plural_quantity, resolve_xml_entity, xml_attribute, PLURAL_QUANTITIES,
+};
+use crate::transform::{get_plural_base_key, split_variables, StringSegment, VariableType};
+use crate::types::{ParseOptions, ParseResult};
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// 가져올 리소스 파일 하나
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AndroidResourceInput {
+    /// 파일 경로 (예: "res/values-ko/strings.xml")
+    pub path: String,
+    /// XML 내용
+    pub content: String,
+}
+
+/// 리소스 하나
+#[derive(Debug, Clone, PartialEq)]
+enum Resource {
+    String(String),
+    Plurals(Vec<(String, String)>),
+    Array(Vec<String>),
+}
+
+/// 키를 Android 리소스 이름으로 변환
+/// 예: "common.button.submit" -> "common_button_submit", "404.title" -> "_404_title"
+pub fn resource_name(key: &str, separator: &str) -> String {
+    let mut name: String = key
+        .replace(separator, "_")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// 언어 코드를 리소스 디렉토리 이름으로 변환
+/// 예: "ko" -> "values-ko", "pt-BR" -> "values-pt-rBR", 기본 언어 -> "values"
+pub fn values_dir(language: &str, default_language: Option<&str>) -> String {
+    if Some(language) == default_language {
+        return "values".to_string();
+    }
+    match language.split_once('-') {
+        Some((lang, region)) if region.len() == 2 => format!("values-{}-r{}", lang, region),
+        Some((lang, rest)) => format!("values-b+{}+{}", lang, rest.replace('-', "+")),
+        None => format!("values-{}", language),
+    }
+}
+
+/// 리소스 디렉토리 이름에서 언어 코드 추출
+/// `values`나 언어가 아닌 qualifier(`values-night` 등)는 기본 언어로 취급
+pub fn language_from_dir(dir: &str, default_language: &str) -> Option<String> {
+    let qualifiers = dir.strip_prefix("values")?;
+    if let Some(bcp47) = qualifiers.strip_prefix("-b+") {
+        return Some(normalize_lang_code(&bcp47.replace('+', "-")));
+    }
+
+    let mut parts = qualifiers.split('-').filter(|p| !p.is_empty());
+    let lang = match parts.next() {
+        Some(p) if (2..=3).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphabetic()) => p,
+        _ => return Some(default_language.to_string()),
+    };
+    match parts.next().and_then(|r| r.strip_prefix('r')) {
+        Some(region) if region.len() == 2 => Some(normalize_lang_code(&format!("{}-{}", lang, region))),
+        _ => Some(normalize_lang_code(lang)),
+    }
+}
+
+// ============================================================================
+// 내보내기
+// ============================================================================
+
+/// Android 문자열 escape (XML escape 포함)
+fn escape_android(text: &str, at_start: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '@' | '?' if at_start && i == 0 => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    escape(out.as_str()).into_owned()
+}
+
+/// 값을 `<string>` / `<item>` 내용으로 변환
+/// `order`는 위치 인자 번호를 정할 변수 순서 (`placeholder_order` 참고)
+fn to_android_text(value: &str, order: &[String]) -> String {
+    let mut out = String::new();
+    let mut positions: Vec<String> = order.to_vec();
+    let segments = split_variables(value);
+    // 위치 인자를 내보내면 getString(id, args)의 형식 문자열이 되므로 리터럴 %는 %%로
+    let formatted = segments
+        .iter()
+        .any(|s| matches!(s, StringSegment::Variable(v) if v.var_type == VariableType::DoubleBrace));
+    let literal = |text: &str| if formatted { text.replace('%', "%%") } else { text.to_string() };
+
+    for (i, segment) in segments.into_iter().enumerate() {
+        match segment {
+            StringSegment::Variable(var) if var.var_type == VariableType::DoubleBrace => {
+                let name = var.name.unwrap_or_default().trim().to_string();
+                let index = match positions.iter().position(|n| *n == name) {
+                    Some(idx) => idx + 1,
+                    None => {
+                        positions.push(name.clone());
+                        positions.len()
+                    }
+                };
+                let conversion = if name == "count" { 'd' } else { 's' };
+                out.push_str(&format!(
+                    "<xliff:g id=\"{}\">%{}${}</xliff:g>",
+                    escape(name.as_str()),
+                    index,
+                    conversion
+                ));
+            }
+            StringSegment::Variable(var) if var.var_type == VariableType::Printf => {
+                out.push_str(&escape_android(&var.full_match, i == 0))
+            }
+            StringSegment::Variable(var) => out.push_str(&escape_android(&literal(&var.full_match), i == 0)),
+            StringSegment::Text(text) => out.push_str(&escape_android(&literal(&text), i == 0)),
+        }
+    }
+
+    out
+}
+
+/// 한 언어의 리소스 목록 구성 (리소스 이름 -> (원래 키, 리소스))
+fn collect_resources(
+    data: &Map<String, Value>,
+    separator: &str,
+) -> Result<BTreeMap<String, (String, Resource)>> {
+    let flat = flatten_locale(data, separator);
+
+    let mut resources: BTreeMap<String, (String, Resource)> = BTreeMap::new();
+    let mut arrays: BTreeMap<String, Vec<(usize, String)>> = BTreeMap::new();
+    let mut plurals: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+
+    let mut add = |name: String, origin: &str, resource: Resource| -> Result<()> {
+        if let Some((previous, _)) = resources.get(&name) {
+            if previous != origin {
+                return Err(ParseError::new(
+                    ErrorKind::InvalidKeyFormat,
+                    format!(
+                        "Keys '{}' and '{}' both map to Android resource name '{}'",
+                        previous, origin, name
+                    ),
+                )
+                .with_key(origin)
+                .with_suggestion("Rename one of the keys so that they differ after '_' substitution"));
+            }
+        }
+        resources.insert(name, (origin.to_string(), resource));
+        Ok(())
+    };
+
+    for (key, value) in &flat {
+        if let Value::Array(items) = value {
+            let items = items.iter().map(value_to_cell).collect();
+            add(resource_name(key, separator), key, Resource::Array(items))?;
+            continue;
+        }
+
+        let text = value_to_cell(value);
+        if text.is_empty() {
+            continue;
+        }
+
+        // items.0, items.1 ... -> string-array 후보
+        if let Some((prefix, index)) = key.rsplit_once(separator) {
+            if let Ok(index) = index.parse::<usize>() {
+                arrays.entry(prefix.to_string()).or_default().push((index, text));
+                continue;
+            }
+        }
+
+        match plural_quantity(key) {
+            Some((base, quantity)) => plurals.entry(base).or_default().push((quantity, text)),
+            None => add(resource_name(key, separator), key, Resource::String(text))?,
+        }
+    }
+
+    for (base, mut items) in plurals {
+        items.sort_by_key(|(q, _)| PLURAL_QUANTITIES.iter().position(|x| x == q));
+        add(resource_name(&base, separator), &base, Resource::Plurals(items))?;
+    }
+
+    for (prefix, mut items) in arrays {
+        items.sort_by_key(|(i, _)| *i);
+        let contiguous = items.iter().enumerate().all(|(pos, (i, _))| pos == *i);
+        if contiguous {
+            let values = items.into_iter().map(|(_, v)| v).collect();
+            add(resource_name(&prefix, separator), &prefix, Resource::Array(values))?;
+        } else {
+            // 인덱스가 연속되지 않으면 개별 문자열로 유지
+            for (i, v) in items {
+                let key = format!("{}{}{}", prefix, separator, i);
+                add(resource_name(&key, separator), &key, Resource::String(v))?;
+            }
+        }
+    }
+
+    Ok(resources)
+}
+
+/// 한 언어의 `strings.xml` 생성
+pub fn to_strings_xml(result: &ParseResult, language: &str, separator: &str) -> Result<String> {
+    let empty = Map::new();
+    let data = result.data.get(language).unwrap_or(&empty);
+    let resources = collect_resources(data, separator)?;
+    let order = placeholder_order(result, separator);
+    let order_for = |key: &str| order.get(key).map(Vec::as_slice).unwrap_or_default();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    out.push_str("<resources xmlns:xliff=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+    for (name, (origin, resource)) in resources {
+        match resource {
+            Resource::String(value) => {
+                out.push_str(&format!(
+                    "    <string name=\"{}\">{}</string>\n",
+                    name,
+                    to_android_text(&value, order_for(&origin))
+                ));
+            }
+            Resource::Plurals(items) => {
+                out.push_str(&format!("    <plurals name=\"{}\">\n", name));
+                for (quantity, value) in items {
+                    out.push_str(&format!(
+                        "        <item quantity=\"{}\">{}</item>\n",
+                        quantity,
+                        to_android_text(&value, order_for(&origin))
+                    ));
+                }
+                out.push_str("    </plurals>\n");
+            }
+            Resource::Array(items) => {
+                out.push_str(&format!("    <string-array name=\"{}\">\n", name));
+                for (i, value) in items.iter().enumerate() {
+                    let order = order_for(&format!("{}{}{}", origin, separator, i));
+                    out.push_str(&format!("        <item>{}</item>\n", to_android_text(value, order)));
+                }
+                out.push_str("    </string-array>\n");
+            }
+        }
+    }
+    out.push_str("</resources>\n");
+
+    Ok(out)
+}
+
+/// 모든 언어의 `strings.xml` 생성 (경로 -> XML)
+/// 예: { "values/strings.xml": ..., "values-ko/strings.xml": ... }
+pub fn to_resource_files(
+    result: &ParseResult,
+    separator: &str,
+    default_language: Option<&str>,
+) -> Result<BTreeMap<String, String>> {
+    result
+        .languages
+        .iter()
+        .map(|lang| {
+            let path = format!("{}/strings.xml", values_dir(lang, default_language));
+            Ok((path, to_strings_xml(result, lang, separator)?))
+        })
+        .collect()
+}
+
+// ============================================================================
+// 가져오기
+// ============================================================================
+
+/// Android escape 해제 후, 위치 인자가 있던 문자열이면 `%%`를 `%`로 복원
+fn unescape_formatted(text: &str, formatted: bool) -> String {
+    let value = unescape_android(text);
+    if formatted {
+        value.replace("%%", "%")
+    } else {
+        value
+    }
+}
+
+/// Android escape 해제 (전체를 감싼 큰따옴표 제거 포함)
+fn unescape_android(text: &str) -> String {
+    let trimmed = text.trim();
+    let text = if trimmed.len() >= 2 && trimmed.starts_with('"') && trimmed.ends_with('"') {
+        &trimmed[1..trimmed.len() - 1]
+    } else {
+        text
+    };
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(ch) => out.push(ch),
+                    None => {
+                        out.push_str("\\u");
+                        out.push_str(&hex);
+                    }
+                }
+            }
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// `strings.xml` 하나를 리소스 목록으로 파싱
+fn parse_strings_xml(path: &str, content: &str) -> Result<Vec<(String, Resource)>> {
+    let error = |details: String| ParseError::android_resource_error(path, &details);
+
+    let mut reader = Reader::from_str(content);
+    let mut resources = Vec::new();
+    let mut saw_root = false;
+
+    // 현재 리소스: (종류, 이름), 현재 item의 quantity
+    let mut current: Option<(Vec<u8>, String)> = None;
+    let mut quantity: Option<String> = None;
+    let mut plural_items: Vec<(String, String)> = Vec::new();
+    let mut array_items: Vec<String> = Vec::new();
+    let mut text: Option<String> = None;
+    // <xliff:g id="..."> 내부 (원래 변수 이름)
+    let mut placeholder: Option<String> = None;
+    // 현재 텍스트에 위치 인자가 있었는지 (있으면 %%는 리터럴 %)
+    let mut formatted = false;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| error(format!("{} (byte {})", e, reader.buffer_position())))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let local = e.local_name().as_ref().to_vec();
+                match (local.as_slice(), &current, &text) {
+                    (b"resources", _, _) => saw_root = true,
+                    (b"string" | b"plurals" | b"string-array", None, _) => {
+                        let name = xml_attribute(e, "name")
+                            .ok_or_else(|| error("resource without 'name' attribute".to_string()))?;
+                        if is_empty {
+                            if local == b"string" {
+                                resources.push((name, Resource::String(String::new())));
+                            }
+                            continue;
+                        }
+                        if local == b"string" {
+                            text = Some(String::new());
+                            formatted = false;
+                        }
+                        current = Some((local, name));
+                    }
+                    (b"item", Some(_), None) => {
+                        quantity = xml_attribute(e, "quantity");
+                        text = Some(String::new());
+                        formatted = false;
+                        if is_empty {
+                            text = None;
+                            match quantity.take() {
+                                Some(q) => plural_items.push((q, String::new())),
+                                None => array_items.push(String::new()),
+                            }
+                        }
+                    }
+                    (b"g", _, Some(_)) if !is_empty => {
+                        placeholder = Some(xml_attribute(e, "id").unwrap_or_default());
+                    }
+                    (_, _, Some(_)) if placeholder.is_none() => {
+                        // 스타일 태그 (<b>, <i>, ...)는 텍스트로 보존
+                        let raw = String::from_utf8_lossy(e.as_ref()).into_owned();
+                        let buffer = text.as_mut().unwrap();
+                        if is_empty {
+                            buffer.push_str(&format!("<{}/>", raw));
+                        } else {
+                            buffer.push_str(&format!("<{}>", raw));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(ref e) => {
+                let local = e.local_name().as_ref().to_vec();
+                match local.as_slice() {
+                    b"g" if placeholder.is_some() => {
+                        let name = placeholder.take().unwrap_or_default();
+                        if let Some(buffer) = text.as_mut() {
+                            buffer.push_str(&format!("{{{{{}}}}}", name));
+                            formatted = true;
+                        }
+                    }
+                    b"item" if text.is_some() => {
+                        let value = unescape_formatted(&text.take().unwrap_or_default(), formatted);
+                        match quantity.take() {
+                            Some(q) => plural_items.push((q, value)),
+                            None => array_items.push(value),
+                        }
+                    }
+                    b"string" | b"plurals" | b"string-array"
+                        if current.as_ref().is_some_and(|(kind, _)| *kind == local) =>
+                    {
+                        let (_, name) = current.take().unwrap();
+                        let resource = match local.as_slice() {
+                            b"string" => {
+                                Resource::String(unescape_formatted(&text.take().unwrap_or_default(), formatted))
+                            }
+                            b"plurals" => Resource::Plurals(std::mem::take(&mut plural_items)),
+                            _ => Resource::Array(std::mem::take(&mut array_items)),
+                        };
+                        resources.push((name, resource));
+                    }
+                    _ => {
+                        if let (Some(buffer), None) = (text.as_mut(), &placeholder) {
+                            buffer.push_str(&format!("</{}>", String::from_utf8_lossy(&local)));
+                        }
+                    }
+                }
+            }
+            Event::Text(ref t) if placeholder.is_none() => {
+                if let Some(buffer) = text.as_mut() {
+                    buffer.push_str(&t.decode().map_err(|e| error(e.to_string()))?);
+                }
+            }
+            Event::CData(ref t) if placeholder.is_none() => {
+                if let Some(buffer) = text.as_mut() {
+                    buffer.push_str(&t.decode().map_err(|e| error(e.to_string()))?);
+                }
+            }
+            Event::GeneralRef(ref r) if placeholder.is_none() => {
+                if let Some(buffer) = text.as_mut() {
+                    let name = r.decode().map_err(|e| error(e.to_string()))?;
+                    let resolved = resolve_xml_entity(&name)
+                        .ok_or_else(|| error(format!("unknown entity '&{};'", name)))?;
+                    buffer.push_str(&resolved);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !saw_root {
+        return Err(error("missing <resources> root element".to_string()));
+    }
+
+    Ok(resources)
+}
+
+/// `values*/strings.xml` 파일들을 ParseResult로 변환
+///
+/// # Arguments
+/// * `default_language` - `values/` (qualifier 없음) 디렉토리의 언어
+/// * `reference` - 원래 키를 복원할 때 사용할 기존 데이터 (리소스 이름 -> 원래 키).
+///   없으면 리소스 이름을 그대로 키로 사용합니다.
+pub fn parse(
+    files: &[AndroidResourceInput],
+    default_language: &str,
+    reference: Option<&ParseResult>,
+    options: &ParseOptions,
+) -> Result<ParseResult> {
+    if files.is_empty() {
+        return Err(ParseError::empty_data()
+            .with_suggestion("Provide at least one values*/strings.xml file"));
+    }
+
+    // 리소스 이름 -> 원래 키 (복수형 기본 키, 배열 prefix 포함)
+    let mut original_keys: HashMap<String, String> = HashMap::new();
+    for data in reference.map(|r| r.data.values()).into_iter().flatten() {
+        for key in flatten_locale(data, &options.separator).keys() {
+            original_keys.insert(resource_name(key, &options.separator), key.clone());
+            if let Some(base) = get_plural_base_key(key) {
+                original_keys.insert(resource_name(&base, &options.separator), base);
+            }
+            if let Some((prefix, index)) = key.rsplit_once(options.separator.as_str()) {
+                if index.parse::<usize>().is_ok() {
+                    original_keys.insert(resource_name(prefix, &options.separator), prefix.to_string());
+                }
+            }
+        }
+    }
+    let key_for = |name: &str| original_keys.get(name).cloned().unwrap_or_else(|| name.to_string());
+
+    let mut languages: Vec<String> = Vec::new();
+    let mut entries: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    let mut all_keys = std::collections::BTreeSet::new();
+
+    for file in files {
+        let dir = file
+            .path
+            .replace('\\', "/")
+            .split('/')
+            .rev()
+            .find(|part| part.starts_with("values"))
+            .map(|s| s.to_string())
+            .ok_or_else(|| {
+                ParseError::android_resource_error(&file.path, "path must contain a values* directory")
+            })?;
+        let language = language_from_dir(&dir, &normalize_lang_code(default_language))
+            .unwrap_or_else(|| normalize_lang_code(default_language));
+        if !languages.contains(&language) {
+            languages.push(language.clone());
+        }
+
+        let target = entries.entry(language).or_default();
+        for (name, resource) in parse_strings_xml(&file.path, &file.content)? {
+            match resource {
+                Resource::String(value) => {
+                    let key = key_for(&name);
+                    all_keys.insert(key.clone());
+                    if !value.is_empty() {
+                        target.push((key, value));
+                    }
+                }
+                Resource::Plurals(items) => {
+                    let base = key_for(&name);
+                    for (quantity, value) in items {
+                        let key = format!("{}_{}", base, quantity);
+                        all_keys.insert(key.clone());
+                        if !value.is_empty() {
+                            target.push((key, value));
+                        }
+                    }
+                }
+                Resource::Array(items) => {
+                    let prefix = key_for(&name);
+                    for (i, value) in items.into_iter().enumerate() {
+                        let key = format!("{}{}{}", prefix, options.separator, i);
+                        all_keys.insert(key.clone());
+                        if !value.is_empty() {
+                            target.push((key, value));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let data = entries
+        .into_iter()
+        .map(|(lang, values)| Ok((lang, build_lang_map(values, options)?)))
+        .collect::<Result<_>>()?;
+
+    Ok(ParseResult {
+        languages,
+        data,
+        row_count: all_keys.len(),
+        warnings: Vec::new(),
+        metadata: BTreeMap::new(),
+        language_columns: BTreeMap::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result() -> ParseResult {
+        let csv_str = "key,en,ko\n\
+common.hello,Hello {{name}}!,안녕 {{name}}!\n\
+common.quote,It's \"fine\",\n\
+mention,@home,@홈\n\
+cart.items_one,{{count}} item,{{count}}개\n\
+cart.items_other,{{count}} items,{{count}}개\n\
+days.0,Mon,월\n\
+days.1,Tue,화\n\
+404.title,Not found & gone,없음";
+        crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_resource_name() {
+        assert_eq!(resource_name("common.button.submit", "."), "common_button_submit");
+        assert_eq!(resource_name("auth/login-form", "/"), "auth_login_form");
+        assert_eq!(resource_name("404.title", "."), "_404_title");
+    }
+
+    #[test]
+    fn test_values_dir_round_trip() {
+        assert_eq!(values_dir("en", Some("en")), "values");
+        assert_eq!(values_dir("ko", Some("en")), "values-ko");
+        assert_eq!(values_dir("pt-BR", None), "values-pt-rBR");
+
+        assert_eq!(language_from_dir("values", "en").as_deref(), Some("en"));
+        assert_eq!(language_from_dir("values-ko", "en").as_deref(), Some("ko"));
+        assert_eq!(language_from_dir("values-pt-rBR", "en").as_deref(), Some("pt-BR"));
+        assert_eq!(language_from_dir("values-b+sr+Latn", "en").as_deref(), Some("sr-LATN"));
+        assert_eq!(language_from_dir("values-night", "en").as_deref(), Some("en"));
+        assert_eq!(language_from_dir("drawable", "en"), None);
+    }
+
+    #[test]
+    fn test_export_strings_xml() {
+        let files = to_resource_files(&sample_result(), ".", Some("en")).unwrap();
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["values-ko/strings.xml", "values/strings.xml"]
+        );
+
+        let en = files.get("values/strings.xml").unwrap();
+        assert!(en.contains(
+            "<string name=\"common_hello\">Hello <xliff:g id=\"name\">%1$s</xliff:g>!</string>"
+        ));
+        assert!(en.contains("<string name=\"common_quote\">It\\&apos;s \\&quot;fine\\&quot;</string>"));
+        assert!(en.contains("<string name=\"mention\">\\@home</string>"));
+        assert!(en.contains("<plurals name=\"cart_items\">\n        <item quantity=\"one\"><xliff:g id=\"count\">%1$d</xliff:g> item</item>"));
+        assert!(en.contains("<string-array name=\"days\">\n        <item>Mon</item>\n        <item>Tue</item>"));
+        assert!(en.contains("<string name=\"_404_title\">Not found &amp; gone</string>"));
+
+        let ko = files.get("values-ko/strings.xml").unwrap();
+        assert!(!ko.contains("common_quote"), "empty cells are omitted");
+    }
+
+    #[test]
+    fn test_placeholder_positions_follow_source_order() {
+        let csv_str = "key,en,ko\ngreeting,{{name}} has {{count}} messages,메시지 {{count}}개 - {{name}}";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let ko = to_strings_xml(&result, "ko", ".").unwrap();
+        assert!(ko.contains(
+            "메시지 <xliff:g id=\"count\">%2$d</xliff:g>개 - <xliff:g id=\"name\">%1$s</xliff:g>"
+        ));
+    }
+
+    #[test]
+    fn test_literal_percent_with_placeholders() {
+        let csv_str = "key,en\nsale,50% off {{name}}\nplain,100%";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let xml = to_strings_xml(&result, "en", ".").unwrap();
+        assert!(xml.contains("<string name=\"sale\">50%% off <xliff:g id=\"name\">%1$s</xliff:g></string>"));
+        assert!(xml.contains("<string name=\"plain\">100%</string>"));
+
+        let files = vec![AndroidResourceInput { path: "values/strings.xml".to_string(), content: xml }];
+        let back = parse(&files, "en", None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data["en"]["sale"], "50% off {{name}}");
+        assert_eq!(back.data["en"]["plain"], "100%");
+    }
+
+    #[test]
+    fn test_name_collision() {
+        let csv_str = "key,en\na.b,one\na_b,two";
+        let result = crate::parser::csv::parse(
+            csv_str.as_bytes(),
+            &ParseOptions {
+                nested: false,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let err = to_strings_xml(&result, "en", ".").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidKeyFormat);
+    }
+
+    #[test]
+    fn test_round_trip_with_reference() {
+        let original = sample_result();
+        let files: Vec<AndroidResourceInput> = to_resource_files(&original, ".", Some("en"))
+            .unwrap()
+            .into_iter()
+            .map(|(path, content)| AndroidResourceInput {
+                path: format!("app/src/main/res/{}", path),
+                content,
+            })
+            .collect();
+
+        let back = parse(&files, "en", Some(&original), &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("en"), original.data.get("en"));
+        assert_eq!(back.data.get("ko"), original.data.get("ko"));
+    }
+
+    #[test]
+    fn test_import_without_reference() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="welcome">"  Welcome, <xliff:g id="user" example="Bob">%1$s</xliff:g>  "</string>
+    <string name="styled">Tap <b>here</b> !</string>
+    <string name="empty"/>
+    <plurals name="songs">
+        <item quantity="one">One song</item>
+        <item quantity="other">%d songs</item>
+    </plurals>
+</resources>"#;
+        let files = vec![AndroidResourceInput {
+            path: "res/values-ko/strings.xml".to_string(),
+            content: xml.to_string(),
+        }];
+        let options = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
+
+        let result = parse(&files, "en", None, &options).unwrap();
+        assert_eq!(result.languages, vec!["ko"]);
+        let ko = result.data.get("ko").unwrap();
+        assert_eq!(ko.get("welcome").unwrap(), "  Welcome, {{user}}  ");
+        assert_eq!(ko.get("styled").unwrap(), "Tap <b>here</b> !");
+        assert_eq!(ko.get("songs_other").unwrap(), "%d songs");
+        assert!(ko.get("empty").is_none());
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let files = vec![AndroidResourceInput {
+            path: "strings.xml".to_string(),
+            content: "<resources/>".to_string(),
+        }];
+        let err = parse(&files, "en", None, &ParseOptions::default()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::AndroidResourceError);
+
+        let files = vec![AndroidResourceInput {
+            path: "values/strings.xml".to_string(),
+            content: "<foo/>".to_string(),
+        }];
+        assert!(parse(&files, "en", None, &ParseOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,645 @@
+//! Flutter ARB (`app_en.arb`) 가져오기/내보내기
+//!
+//! - 키는 Dart 식별자로 변환 (예: "common.button.submit" -> "commonButtonSubmit")
+//! - `{{name}}` <-> `{name}`
+//! - 복수형 키(`items_one`, `items_other`)는 ICU `{count, plural, one{...} other{...}}` 메시지로 묶음
+//! - `@key` 메타데이터에 키 메타데이터의 `description`/`context`와 `placeholders` 기록,
+//!   가져올 때는 `description`을 별도 열로 유지
+
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::export::{flatten_locale, merge_jsons_to_table, value_to_cell, LangJsonInput, TableData};
+use crate::lang_codes::normalize_lang_code;
+use crate::parser::{key_metadata, plural_quantity, PLURAL_QUANTITIES};
+use crate::transform::{extract_variable_names, get_plural_base_key};
+use crate::types::{KeyMetadata, ParseResult};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// 가져온 표에 추가되는 설명 열 이름
+pub const DESCRIPTION_COLUMN: &str = "description";
+
+/// 가져올 ARB 파일 하나
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArbInput {
+    /// 언어 코드 (없으면 `@@locale` 사용)
+    #[serde(default, alias = "lang")]
+    pub language: Option<String>,
+    /// ARB(JSON) 내용
+    #[serde(alias = "data")]
+    pub content: String,
+}
+
+/// 키를 ARB 메시지 이름(Dart 식별자)으로 변환
+/// 예: "common.button.submit" -> "commonButtonSubmit", "404.title" -> "key404Title"
+pub fn arb_key(key: &str, separator: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+    for part in key.split(separator) {
+        for c in part.chars() {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                if upper {
+                    out.push(c.to_ascii_uppercase());
+                    upper = false;
+                } else {
+                    out.push(c);
+                }
+            } else {
+                upper = !out.is_empty();
+            }
+        }
+        upper = !out.is_empty();
+    }
+
+    match out.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("key{}", out),
+        Some(c) => format!("{}{}", c.to_ascii_lowercase(), &out[1..]),
+        None => "key".to_string(),
+    }
+}
+
+/// ARB 파일에 쓰는 locale 값 (예: "pt-BR" -> "pt_BR")
+pub fn arb_locale(language: &str) -> String {
+    normalize_lang_code(language).replace('-', "_")
+}
+
+// ============================================================================
+// 내보내기
+// ============================================================================
+
+/// `{{name}}` -> `{name}`
+///
+/// 나머지 텍스트의 ICU 특수 문자(`'`, `{`, `}`, plural 분기의 `#`)는 따옴표로 감쌉니다.
+/// `{gender, select, ...}` 같은 ICU 인자는 그대로 둡니다.
+fn to_arb_message(value: &str, plural: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(name) = double_brace_name(rest) {
+            out.push_str(&format!("{{{}}}", name.trim()));
+            rest = &rest[name.len() + 4..];
+            continue;
+        }
+        if let Some(close) = icu_argument_end(rest) {
+            out.push_str(&rest[..=close]);
+            rest = &rest[close + 1..];
+            continue;
+        }
+        match c {
+            '\'' => out.push_str("''"),
+            '{' | '}' => out.push_str(&format!("'{}'", c)),
+            '#' if plural => out.push_str("'#'"),
+            _ => out.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// `{{name}}`으로 시작하면 중괄호 안의 내용
+fn double_brace_name(text: &str) -> Option<&str> {
+    let inner = text.strip_prefix("{{")?;
+    let end = inner.find('}')?;
+    (end > 0 && inner[end..].starts_with("}}")).then(|| &inner[..end])
+}
+
+/// `{` 위치에서 짝이 맞는 `}`의 위치 (ICU 따옴표 구간은 건너뜀)
+fn matching_brace(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quoted = false;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => match chars.peek().map(|(_, next)| *next) {
+                Some('\'') => {
+                    chars.next();
+                }
+                Some('{' | '}' | '#' | '|') if !quoted => quoted = true,
+                _ if quoted => quoted = false,
+                _ => {}
+            },
+            _ if quoted => {}
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// ICU 인자/ARB placeholder 이름 (영문, 숫자, `_`)
+fn is_argument_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `{name, select|plural|selectordinal, ...}` 형태의 ICU 인자이면 닫는 `}`의 위치
+fn icu_argument_end(text: &str) -> Option<usize> {
+    let (name, rest) = text.strip_prefix('{')?.split_once(',')?;
+    let name = name.trim();
+    if !is_argument_name(name) {
+        return None;
+    }
+    let (kind, _) = rest.split_once(',')?;
+    if !matches!(kind.trim(), "select" | "plural" | "selectordinal") {
+        return None;
+    }
+    matching_brace(text)
+}
+
+/// ARB 메시지(`to_arb_message` 결과)에 사용된 `{name}` 인자로 `placeholders` 메타데이터 생성
+///
+/// 따옴표로 감싼 리터럴 중괄호(`'{'braces'}'`)와 ICU 인자는 이름이 식별자가 아니므로 제외됩니다.
+fn placeholders<'a>(values: impl IntoIterator<Item = &'a str>, plural: bool) -> Map<String, Value> {
+    let mut map = Map::new();
+    if plural {
+        map.insert("count".to_string(), json!({ "type": "int" }));
+    }
+    for value in values {
+        for name in extract_variable_names(value) {
+            let name = name.trim().to_string();
+            if !is_argument_name(&name) {
+                continue;
+            }
+            if !map.contains_key(&name) {
+                let kind = if name == "count" { "int" } else { "String" };
+                map.insert(name, json!({ "type": kind }));
+            }
+        }
+    }
+    map
+}
+
+/// `@메시지` 메타데이터 (`description`, `context`, `placeholders` 중 값이 있는 것)
+fn message_metadata(metadata: Option<&KeyMetadata>, placeholders: Map<String, Value>) -> Map<String, Value> {
+    let mut meta = Map::new();
+    if let Some(metadata) = metadata {
+        if let Some(description) = &metadata.description {
+            meta.insert("description".to_string(), Value::String(description.clone()));
+        }
+        if let Some(context) = &metadata.context {
+            meta.insert("context".to_string(), Value::String(context.clone()));
+        }
+    }
+    if !placeholders.is_empty() {
+        meta.insert("placeholders".to_string(), Value::Object(placeholders));
+    }
+    meta
+}
+
+/// 한 언어의 ARB 파일 생성
+///
+/// `@@locale` 다음에 메시지와 `@메시지` 메타데이터가 키 순서대로 이어집니다.
+pub fn to_arb(result: &ParseResult, language: &str, separator: &str) -> Result<String> {
+    let empty = Map::new();
+    let data = result.data.get(language).unwrap_or(&empty);
+
+    // 메시지 이름 -> (원래 키, 메시지, 메타데이터)
+    let mut messages: BTreeMap<String, (String, String, Map<String, Value>)> = BTreeMap::new();
+    let mut plurals: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+
+    let mut add = |name: String, origin: &str, message: String, meta: Map<String, Value>| -> Result<()> {
+        if let Some((previous, _, _)) = messages.get(&name) {
+            if previous != origin {
+                return Err(ParseError::new(
+                    ErrorKind::InvalidKeyFormat,
+                    format!("Keys '{}' and '{}' both map to ARB message '{}'", previous, origin, name),
+                )
+                .with_key(origin)
+                .with_suggestion("Rename one of the keys so that they differ after camelCase conversion"));
+            }
+        }
+        messages.insert(name, (origin.to_string(), message, meta));
+        Ok(())
+    };
+
+    for (key, value) in flatten_locale(data, separator) {
+        let text = value_to_cell(&value);
+        if text.is_empty() {
+            continue;
+        }
+        match plural_quantity(&key) {
+            Some((base, quantity)) => plurals.entry(base).or_default().push((quantity, text)),
+            None => {
+                let message = to_arb_message(&text, false);
+                let meta = message_metadata(result.metadata.get(&key), placeholders([message.as_str()], false));
+                add(arb_key(&key, separator), &key, message, meta)?;
+            }
+        }
+    }
+
+    for (base, mut items) in plurals {
+        items.sort_by_key(|(q, _)| PLURAL_QUANTITIES.iter().position(|x| x == q));
+        let metadata = key_metadata(result, &base);
+        let messages: Vec<String> = items.iter().map(|(_, value)| to_arb_message(value, true)).collect();
+        let meta = message_metadata(metadata, placeholders(messages.iter().map(String::as_str), true));
+        let branches: Vec<String> = items
+            .iter()
+            .zip(&messages)
+            .map(|((quantity, _), message)| format!("{}{{{}}}", quantity, message))
+            .collect();
+        let message = format!("{{count, plural, {}}}", branches.join(" "));
+        add(arb_key(&base, separator), &base, message, meta)?;
+    }
+
+    let mut out = String::from("{\n");
+    out.push_str(&format!("  \"@@locale\": {}", Value::String(arb_locale(language))));
+    for (name, (_, message, meta)) in messages {
+        out.push_str(&format!(",\n  {}: {}", Value::String(name.clone()), Value::String(message)));
+        if !meta.is_empty() {
+            let pretty = serde_json::to_string_pretty(&meta).map_err(ParseError::json_serialize_error)?;
+            out.push_str(&format!(
+                ",\n  {}: {}",
+                Value::String(format!("@{}", name)),
+                pretty.replace('\n', "\n  ")
+            ));
+        }
+    }
+    out.push_str("\n}\n");
+
+    Ok(out)
+}
+
+/// 모든 언어의 ARB 파일 생성 (파일 이름 -> 내용)
+/// 예: `prefix = "app"` -> { "app_en.arb": ..., "app_pt_BR.arb": ... }
+pub fn to_arb_files(result: &ParseResult, separator: &str, prefix: &str) -> Result<BTreeMap<String, String>> {
+    result
+        .languages
+        .iter()
+        .map(|lang| {
+            let name = format!("{}_{}.arb", prefix, arb_locale(lang));
+            Ok((name, to_arb(result, lang, separator)?))
+        })
+        .collect()
+}
+
+// ============================================================================
+// 가져오기
+// ============================================================================
+
+/// `{name}` -> `{{name}}`
+///
+/// 최상위의 단순 인자만 변환하고 ICU 따옴표(`''`, `'{'` 등)는 해제합니다.
+/// `{gender, select, ...}` 같은 중첩 인자는 그대로 둡니다.
+fn from_arb_message(message: &str, plural: bool) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\'' if rest.starts_with("''") => {
+                out.push('\'');
+                rest = &rest[2..];
+            }
+            '\'' if rest[1..].starts_with(['{', '}']) || (plural && rest[1..].starts_with('#')) => {
+                // 닫는 따옴표까지 리터럴 ('' 는 ')
+                rest = &rest[1..];
+                loop {
+                    match rest.find('\'') {
+                        Some(idx) if rest[idx + 1..].starts_with('\'') => {
+                            out.push_str(&rest[..=idx]);
+                            rest = &rest[idx + 2..];
+                        }
+                        Some(idx) => {
+                            out.push_str(&rest[..idx]);
+                            rest = &rest[idx + 1..];
+                            break;
+                        }
+                        None => {
+                            out.push_str(rest);
+                            rest = "";
+                            break;
+                        }
+                    }
+                }
+            }
+            '{' => {
+                let ident_len = rest[1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len() - 1);
+                if ident_len > 0 && rest[1 + ident_len..].starts_with('}') {
+                    out.push_str(&format!("{{{{{}}}}}", &rest[1..1 + ident_len]));
+                    rest = &rest[ident_len + 2..];
+                } else {
+                    // 중첩 인자는 닫는 괄호까지 그대로 유지
+                    let end = matching_brace(rest).map(|i| i + 1).unwrap_or(rest.len());
+                    out.push_str(&rest[..end]);
+                    rest = &rest[end..];
+                }
+            }
+            _ => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+/// 메시지 전체가 ICU plural 하나인 경우 (카테고리, 메시지) 목록으로 분리
+/// 예: "{count, plural, =0{없음} one{{count}개} other{{count}개}}"
+fn parse_icu_plural(message: &str) -> Option<Vec<(String, String)>> {
+    let inner = message.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let (variable, rest) = inner.split_once(',')?;
+    if !is_argument_name(variable.trim()) {
+        return None;
+    }
+    let mut rest = rest.trim_start().strip_prefix("plural")?.trim_start().strip_prefix(',')?;
+
+    let mut branches: Vec<(String, String)> = Vec::new();
+    // `=0`/`=1`/`=2`는 같은 카테고리 분기가 없을 때만 zero/one/two로 사용
+    let mut exact = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let open = rest.find('{')?;
+        let selector = rest[..open].trim();
+        let close = open + matching_brace(&rest[open..])?;
+        let branch = rest[open + 1..close].to_string();
+        match selector {
+            "=0" => exact.push(("zero", branch)),
+            "=1" => exact.push(("one", branch)),
+            "=2" => exact.push(("two", branch)),
+            q if PLURAL_QUANTITIES.contains(&q) => branches.push((q.to_string(), branch)),
+            _ => return None,
+        }
+        rest = &rest[close + 1..];
+    }
+    for (quantity, branch) in exact {
+        if !branches.iter().any(|(q, _)| q == quantity) {
+            branches.push((quantity.to_string(), branch));
+        }
+    }
+
+    (!branches.is_empty()).then_some(branches)
+}
+
+/// ARB 파일들을 `key,lang...,description` 표로 변환
+///
+/// 각 파일은 `LangJsonInput`으로 바꿔 `export::merge_jsons_to_table`로 병합하고,
+/// `@key.description`은 마지막 `description` 열에 넣습니다.
+///
+/// # Arguments
+/// * `reference` - 원래 키를 복원할 때 사용할 기존 데이터 (없으면 ARB 메시지 이름 사용)
+/// * `separator` - 키 구분자
+pub fn parse_arb_files(inputs: &[ArbInput], reference: Option<&ParseResult>, separator: &str) -> Result<TableData> {
+    // ARB 메시지 이름 -> 원래 키
+    let mut original_keys: HashMap<String, String> = HashMap::new();
+    for data in reference.map(|r| r.data.values()).into_iter().flatten() {
+        for key in flatten_locale(data, separator).keys() {
+            original_keys.insert(arb_key(key, separator), key.clone());
+            if let Some(base) = get_plural_base_key(key) {
+                original_keys.insert(arb_key(&base, separator), base);
+            }
+        }
+    }
+    let key_for = |name: &str| original_keys.get(name).cloned().unwrap_or_else(|| name.to_string());
+
+    let mut descriptions: HashMap<String, String> = HashMap::new();
+    let mut lang_inputs = Vec::with_capacity(inputs.len());
+
+    for (i, input) in inputs.iter().enumerate() {
+        let context = input.language.clone().unwrap_or_else(|| format!("#{}", i + 1));
+        let value: Value =
+            serde_json::from_str(&input.content).map_err(|e| ParseError::json_parse_error(&context, e))?;
+        let root = value.as_object().ok_or_else(|| ParseError::invalid_json_root(&context))?;
+
+        let language = input
+            .language
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .or_else(|| root.get("@@locale").and_then(Value::as_str))
+            .map(normalize_lang_code)
+            .ok_or_else(|| ParseError::arb_parse_error(&context, "missing '@@locale'"))?;
+
+        let mut messages = Map::new();
+        for (name, value) in root {
+            if let Some(meta_name) = name.strip_prefix('@') {
+                if let Some(description) = value.get("description").and_then(Value::as_str) {
+                    descriptions
+                        .entry(key_for(meta_name))
+                        .or_insert_with(|| description.to_string());
+                }
+                continue;
+            }
+            let Some(message) = value.as_str() else {
+                return Err(ParseError::arb_parse_error(
+                    &language,
+                    &format!("message '{}' must be a string", name),
+                ));
+            };
+
+            let key = key_for(name);
+            match parse_icu_plural(message) {
+                Some(branches) => {
+                    for (quantity, text) in branches {
+                        messages.insert(format!("{}_{}", key, quantity), Value::String(from_arb_message(&text, true)));
+                    }
+                }
+                None => {
+                    messages.insert(key, Value::String(from_arb_message(message, false)));
+                }
+            }
+        }
+
+        lang_inputs.push(LangJsonInput {
+            language,
+            content: Value::Object(messages).to_string(),
+        });
+    }
+
+    let mut table = merge_jsons_to_table(&lang_inputs, separator)?;
+    table.header.push(DESCRIPTION_COLUMN.to_string());
+    for row in &mut table.rows {
+        let key = &row[0];
+        let description = descriptions
+            .get(key)
+            .or_else(|| plural_quantity(key).and_then(|(base, _)| descriptions.get(&base)))
+            .cloned()
+            .unwrap_or_default();
+        row.push(description);
+    }
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ParseOptions;
+
+    fn sample_result() -> ParseResult {
+        let csv_str = "key,en,pt-BR\n\
+common.hello,Hello {{name}}!,Olá {{name}}!\n\
+cart.items_one,{{count}} item,{{count}} item\n\
+cart.items_other,{{count}} items,{{count}} itens\n\
+404.title,Not found,";
+        crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_arb_key() {
+        assert_eq!(arb_key("common.button.submit", "."), "commonButtonSubmit");
+        assert_eq!(arb_key("auth/login-form", "/"), "authLoginForm");
+        assert_eq!(arb_key("Settings.title", "."), "settingsTitle");
+        assert_eq!(arb_key("404.title", "."), "key404Title");
+    }
+
+    #[test]
+    fn test_to_arb() {
+        let arb = to_arb(&sample_result(), "pt-BR", ".").unwrap();
+        let value: Value = serde_json::from_str(&arb).unwrap();
+
+        assert!(arb.starts_with("{\n  \"@@locale\": \"pt_BR\",\n  \"cartItems\""));
+        assert_eq!(value["commonHello"], "Olá {name}!");
+        assert_eq!(value["@commonHello"]["placeholders"]["name"]["type"], "String");
+        assert_eq!(value["cartItems"], "{count, plural, one{{count} item} other{{count} itens}}");
+        assert_eq!(value["@cartItems"]["placeholders"]["count"]["type"], "int");
+        assert!(value.get("key404Title").is_none(), "empty cells are omitted");
+
+        let files = to_arb_files(&sample_result(), ".", "app").unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["app_en.arb", "app_pt_BR.arb"]);
+    }
+
+    #[test]
+    fn test_to_arb_metadata() {
+        let csv_str = "key,en,description,#context\n\
+                       common.hello,Hello {{name}}!,Home greeting,Header\n\
+                       cart.items_one,{{count}} item,Cart badge,\n\
+                       cart.items_other,{{count}} items,,";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        assert_eq!(result.languages, vec!["en"]);
+
+        let value: Value = serde_json::from_str(&to_arb(&result, "en", ".").unwrap()).unwrap();
+        assert_eq!(value["@commonHello"]["description"], "Home greeting");
+        assert_eq!(value["@commonHello"]["context"], "Header");
+        assert_eq!(value["@commonHello"]["placeholders"]["name"]["type"], "String");
+        assert_eq!(value["@cartItems"]["description"], "Cart badge");
+    }
+
+    #[test]
+    fn test_round_trip_with_reference() {
+        let original = sample_result();
+        let inputs: Vec<ArbInput> = to_arb_files(&original, ".", "app")
+            .unwrap()
+            .into_values()
+            .map(|content| ArbInput { language: None, content })
+            .collect();
+
+        let table = parse_arb_files(&inputs, Some(&original), ".").unwrap();
+        assert_eq!(table.header, vec!["key", "en", "pt-BR", "description"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["404.title", "Not found", "", ""],
+                vec!["cart.items_one", "{{count}} item", "{{count}} item", ""],
+                vec!["cart.items_other", "{{count}} items", "{{count}} itens", ""],
+                vec!["common.hello", "Hello {{name}}!", "Olá {{name}}!", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_icu_quoting_round_trip() {
+        assert_eq!(to_arb_message("It's {{name}}'s {braces}", false), "It''s {name}''s '{'braces'}'");
+        assert_eq!(to_arb_message("#{{count}} items", true), "'#'{count} items");
+        assert_eq!(from_arb_message("It''s {name}''s '{'braces'}'", false), "It's {{name}}'s {braces}");
+        assert_eq!(from_arb_message("'#'{count} items", true), "#{{count}} items");
+        assert_eq!(from_arb_message("'{literal}' {name}", false), "{literal} {{name}}");
+
+        let csv_str = "key,en\nnote,Use {braces} and 'quotes' for {{name}}";
+        let original = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let content = to_arb(&original, "en", ".").unwrap();
+        let value: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(value["@note"]["placeholders"].as_object().unwrap().len(), 1);
+
+        let table = parse_arb_files(&[ArbInput { language: None, content }], None, ".").unwrap();
+        assert_eq!(table.rows, vec![vec!["note", "Use {braces} and 'quotes' for {{name}}", ""]]);
+    }
+
+    #[test]
+    fn test_select_message_is_preserved() {
+        let select = "{gender, select, male{{name} updated his {count} files} female{{name} updated her files} other{{name} updated their files}}";
+        let en = format!(r#"{{"@@locale": "en", "status": "Hi {{user}}: {}"}}"#, select);
+
+        let table = parse_arb_files(&[ArbInput { language: None, content: en }], None, ".").unwrap();
+        let imported = format!("Hi {{{{user}}}}: {}", select);
+        assert_eq!(table.rows, vec![vec!["status".to_string(), imported.clone(), String::new()]]);
+
+        let csv_str = format!("key,en\nstatus,\"{}\"", imported);
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let value: Value = serde_json::from_str(&to_arb(&result, "en", ".").unwrap()).unwrap();
+        assert_eq!(value["status"], format!("Hi {{user}}: {}", select));
+    }
+
+    #[test]
+    fn test_import_descriptions() {
+        let en = r#"{
+  "@@locale": "en",
+  "greeting": "Hi {userName}, {{legacy}}",
+  "@greeting": { "description": "Home screen greeting", "placeholders": { "userName": {} } },
+  "inbox": "{count, plural, =0{No mail} one{One mail} other{{count} mails}}",
+  "@inbox": { "description": "Inbox counter" }
+}"#;
+        let ko = r#"{ "greeting": "{userName}님 안녕하세요" }"#;
+        let inputs = vec![
+            ArbInput { language: None, content: en.to_string() },
+            ArbInput { language: Some("ko".to_string()), content: ko.to_string() },
+        ];
+
+        let table = parse_arb_files(&inputs, None, ".").unwrap();
+        assert_eq!(table.header, vec!["key", "en", "ko", "description"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["greeting", "Hi {{userName}}, {{legacy}}", "{{userName}}님 안녕하세요", "Home screen greeting"],
+                vec!["inbox_one", "One mail", "", "Inbox counter"],
+                vec!["inbox_other", "{{count}} mails", "", "Inbox counter"],
+                vec!["inbox_zero", "No mail", "", "Inbox counter"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_icu_exact_selectors_fall_back() {
+        let branches = |message: &str| -> Vec<(String, String)> {
+            let mut branches = parse_icu_plural(message).unwrap();
+            branches.sort();
+            branches
+        };
+        let pair = |q: &str, m: &str| (q.to_string(), m.to_string());
+
+        // 카테고리 분기가 있으면 `=1`보다 우선
+        assert_eq!(
+            branches("{count, plural, =1{Exactly one} one{One} other{Many}}"),
+            vec![pair("one", "One"), pair("other", "Many")]
+        );
+        assert_eq!(
+            branches("{count, plural, one{One} =1{Exactly one} =0{None} other{Many}}"),
+            vec![pair("one", "One"), pair("other", "Many"), pair("zero", "None")]
+        );
+        // 없으면 `=1`을 one으로
+        assert_eq!(
+            branches("{count, plural, =1{Exactly one} other{Many}}"),
+            vec![pair("one", "Exactly one"), pair("other", "Many")]
+        );
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let inputs = vec![ArbInput { language: None, content: r#"{"a": "b"}"#.to_string() }];
+        let err = parse_arb_files(&inputs, None, ".").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ArbParseError);
+
+        let inputs = vec![ArbInput { language: Some("en".to_string()), content: r#"{"a": 1}"#.to_string() }];
+        assert!(parse_arb_files(&inputs, None, ".").is_err());
+    }
+}
@@ -1,13 +1,24 @@
-use crate::error::Result;
-use crate::parser::validate_header;
-use crate::transform::process_escape_sequences;
-use crate::types::{LocaleData, ParseOptions, ParseResult};
+use crate::error::{ParseError, Result};
+use crate::parser::{validate_header, CollectedRows, RowCollector};
+use crate::types::{ParseOptions, ParseReport, ParseResult};
 use csv::ReaderBuilder;
-use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Cursor;
 
 /// CSV 데이터 파싱
 pub fn parse(data: &[u8], options: &ParseOptions) -> Result<ParseResult> {
+    collect_rows(data, options, false)?.into_result(options)
+}
+
+/// 진단 모드 파싱: 첫 에러에서 멈추지 않고 모든 행의 문제를 수집
+pub fn parse_report(data: &[u8], options: &ParseOptions) -> ParseReport {
+    match collect_rows(data, options, true) {
+        Ok(collected) => collected.into_report(options),
+        Err(e) => ParseReport::failed(e),
+    }
+}
+
+/// 모든 데이터 행을 읽어 flat 데이터와 에러 목록으로 수집 (헤더 에러만 즉시 반환)
+fn collect_rows(data: &[u8], options: &ParseOptions, diagnostic: bool) -> Result<CollectedRows> {
     let mut reader = ReaderBuilder::new()
         .has_headers(true)
         .flexible(true)
@@ -22,176 +33,39 @@ pub fn parse(data: &[u8], options: &ParseOptions) -> Result<ParseResult> {
 
     // 헤더 검증
     let header_info = validate_header(&headers)?;
+    let mut collector = RowCollector::new(header_info, options);
 
-    // 1단계: 모든 데이터를 flat하게 수집
-    let mut flat_data: HashMap<String, Vec<(String, String)>> = HashMap::new();
-    for lang in &header_info.languages {
-        flat_data.insert(lang.clone(), Vec::new());
-    }
-
-    // separator 검증용
-    let mut separators_found: HashSet<char> = HashSet::new();
-    let mut first_offending_row: Option<usize> = None;
-    let expected_sep = options.separator.chars().next().unwrap_or('.');
-
-    let mut row_count = 0;
     for result in reader.records() {
-        let record = result?;
-        row_count += 1;
+        collector.next_row();
+        let record = match result {
+            Ok(record) => record,
+            Err(e) if diagnostic => {
+                collector.add_error(ParseError::csv_parse_error(&e));
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        // 모든 에러의 행 번호는 레코드가 시작하는 실제 줄 (헤더 = 1, 여러 줄 셀도 반영)
+        let line = record.position().map_or(1, |p| p.line() as usize);
 
         // 컬럼 개수 불일치 검사 (헤더 기준)
         if record.len() != headers.len() {
-            // csv::Position line은 header 포함 1-based, 여기 row_count는 data row 기준 1-based => line = row_count + 1
-            let line_number = row_count + 1;
-            return Err(crate::error::ParseError::column_count_mismatch(
-                line_number,
+            collector.add_error(ParseError::column_count_mismatch(
+                line,
                 headers.len(),
                 record.len(),
             ));
-        }
-
-        let key = match record.get(0) {
-            Some(k) => k.trim(),
-            None => continue,
-        };
-
-        if key.is_empty() {
-            continue;
-        }
-
-        // key 안의 구분자 추출
-        for ch in key.chars() {
-            if ch == '.' || ch == '/' || ch == '-' {
-                separators_found.insert(ch);
-                if ch != expected_sep && first_offending_row.is_none() {
-                    first_offending_row = Some(row_count);
-                }
-            }
-        }
-
-        for lang in &header_info.languages {
-            let col_idx = header_info.language_indices[lang];
-            let raw_value = record.get(col_idx).unwrap_or("").trim();
-
-            if raw_value.is_empty() {
-                continue;
-            }
-
-            let value = if options.process_escapes {
-                process_escape_sequences(raw_value)
-            } else {
-                raw_value.to_string()
-            };
-
-            flat_data.get_mut(lang).unwrap().push((key.to_string(), value));
-        }
-    }
-
-    // 구분자 혼재 여부 체크
-    let invalid: Vec<char> = separators_found
-        .iter()
-        .cloned()
-        .filter(|c| *c != expected_sep)
-        .collect();
-    if !invalid.is_empty() {
-        return Err(crate::error::ParseError::mixed_separators(
-            invalid,
-            &options.separator,
-            first_offending_row,
-        ));
-    }
-
-    // 2단계: nested 또는 flat으로 변환
-    let mut locale_data = if options.nested {
-        build_nested_locale_data(flat_data, &options.separator)
-    } else {
-        build_flat_locale_data(flat_data)
-    };
-
-    // 알파벳 순 정렬
-    locale_data = crate::transform::sort_locale_data(locale_data);
-
-    Ok(ParseResult {
-        languages: header_info.languages,
-        data: locale_data,
-        row_count,
-    })
-}
-
-/// Flat 데이터를 그대로 LocaleData로 변환
-fn build_flat_locale_data(flat_data: HashMap<String, Vec<(String, String)>>) -> LocaleData {
-    let mut result: LocaleData = BTreeMap::new();
-
-    for (lang, entries) in flat_data {
-        let mut lang_map: BTreeMap<String, serde_json::Value> = BTreeMap::new();
-        for (key, value) in entries {
-            lang_map.insert(key, serde_json::Value::String(value));
-        }
-        result.insert(lang, lang_map);
-    }
-
-    result
-}
-
-/// Flat 데이터를 nested 구조로 변환 (한 번에 처리)
-fn build_nested_locale_data(
-    flat_data: HashMap<String, Vec<(String, String)>>,
-    separator: &str,
-) -> LocaleData {
-    let mut result: LocaleData = BTreeMap::new();
-
-    for (lang, entries) in flat_data {
-        let mut root: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
-
-        for (key, value) in entries {
-            if key.contains(separator) {
-                insert_nested(&mut root, &key, value, separator);
-            } else {
-                root.insert(key, serde_json::Value::String(value));
+            if !diagnostic {
+                break;
             }
+            continue;
         }
 
-        // serde_json::Map을 HashMap으로 변환
-        let mut lang_map: BTreeMap<String, serde_json::Value> = BTreeMap::new();
-        for (k, v) in root {
-            lang_map.insert(k, crate::transform::sort_value(v));
-        }
-        result.insert(lang, lang_map);
+        collector.push_row(line, |col| record.get(col).unwrap_or("").trim().to_string());
     }
 
-    result
-}
-
-/// Nested 키를 JSON 구조에 삽입
-fn insert_nested(
-    root: &mut serde_json::Map<String, serde_json::Value>,
-    key: &str,
-    value: String,
-    separator: &str,
-) {
-    let parts: Vec<&str> = key.split(separator).collect();
-    let mut current = root;
-
-    for (i, part) in parts.iter().enumerate() {
-        if i == parts.len() - 1 {
-            // 마지막 파트: 값 삽입
-            current.insert(part.to_string(), serde_json::Value::String(value));
-            return;
-        }
-
-        // 중간 파트: object 생성 또는 기존 object 사용
-        if !current.contains_key(*part) {
-            current.insert(
-                part.to_string(),
-                serde_json::Value::Object(serde_json::Map::new()),
-            );
-        }
-
-        current = current
-            .get_mut(*part)
-            .and_then(|v| v.as_object_mut())
-            .expect("Expected object for nested key");
-    }
+    Ok(collector.finish(diagnostic))
 }
 
 /// CSV 헤더만 파싱하여 언어 목록 반환
@@ -213,6 +87,8 @@ pub fn parse_header_only(data: &[u8]) -> Result<Vec<String>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorKind;
+    use crate::types::{DuplicateKeyPolicy, KeyMetadata, KeyOrder};
 
     #[test]
     fn test_parse_simple_csv() {
@@ -421,4 +297,343 @@ auth/login,Login,로그인"#;
         let err = parse(csv_str.as_bytes(), &options).unwrap_err();
         assert_eq!(err.kind, crate::error::ErrorKind::ColumnCountMismatch);
     }
+
+    #[test]
+    fn test_parse_report_collects_all_errors() {
+        let csv_str = "key,en,xx\n\
+common.hello,Hello,a\n\
+broken,Only one\n\
+auth/login,Login,b\n\
+also broken\n\
+auth-logout,Logout,c";
+
+        let report = parse_report(csv_str.as_bytes(), &ParseOptions::default());
+        assert!(!report.is_ok());
+        assert!(report.result.is_none());
+
+        let kinds: Vec<(ErrorKind, Option<usize>)> = report
+            .errors
+            .iter()
+            .map(|e| (e.kind.clone(), e.location.as_ref().and_then(|l| l.row)))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ErrorKind::ColumnCountMismatch, Some(3)),
+                (ErrorKind::ColumnCountMismatch, Some(5)),
+                (ErrorKind::MixedSeparators, Some(4)),
+                (ErrorKind::MixedSeparators, Some(6)),
+            ]
+        );
+
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].kind, ErrorKind::UnknownLanguageCode);
+        assert_eq!(
+            report.warnings[0].location.as_ref().unwrap().column_name.as_deref(),
+            Some("xx")
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert!(json["result"].is_null());
+        assert_eq!(json["errors"].as_array().unwrap().len(), 4);
+        assert_eq!(json["errors"][0]["kind"], "COLUMN_COUNT_MISMATCH");
+    }
+
+    #[test]
+    fn test_parse_report_ok() {
+        let report = parse_report(b"key,en\nhello,Hello", &ParseOptions::default());
+        assert!(report.is_ok());
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.result.unwrap().row_count, 1);
+
+        let report = parse_report(b"id,en\nhello,Hello", &ParseOptions::default());
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].kind, ErrorKind::InvalidKeyColumn);
+    }
+
+    #[test]
+    fn test_parse_returns_header_warnings() {
+        let result = parse(b"key,en,xx,en\nhello,Hello,Hi,Hallo", &ParseOptions::default()).unwrap();
+
+        assert_eq!(result.languages, vec!["en", "xx"]);
+        assert_eq!(result.data["en"]["hello"], "Hello");
+
+        let warnings: Vec<(ErrorKind, Option<usize>)> = result
+            .warnings
+            .iter()
+            .map(|w| (w.kind.clone(), w.location.as_ref().and_then(|l| l.column)))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                (ErrorKind::UnknownLanguageCode, Some(3)),
+                (ErrorKind::DuplicateLanguageColumn, Some(4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metadata_columns() {
+        let csv = "key,en,#description,ko,context,#maxLength,#tags,#owner\n\
+                   hello,Hello,Greeting on home,안녕,Header,12,\"home, short\",me\n\
+                   bye,Bye,,잘가,,many,,\n\
+                   plain,Plain,,평범,,,,";
+        let result = parse(csv.as_bytes(), &ParseOptions::default()).unwrap();
+
+        assert_eq!(result.languages, vec!["en", "ko"]);
+        assert_eq!(result.data["ko"]["hello"], "안녕");
+        assert_eq!(
+            result.metadata["hello"],
+            KeyMetadata {
+                description: Some("Greeting on home".into()),
+                context: Some("Header".into()),
+                max_length: Some(12),
+                tags: vec!["home".into(), "short".into()],
+            }
+        );
+        assert!(!result.metadata.contains_key("bye"));
+        assert!(!result.metadata.contains_key("plain"));
+
+        // 숫자가 아닌 최대 길이는 경고 후 무시
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, ErrorKind::InvalidMetadataValue);
+        let location = result.warnings[0].location.as_ref().unwrap();
+        assert_eq!((location.row, location.column), (Some(3), Some(6)));
+    }
+
+    #[test]
+    fn test_duplicate_key_policies() {
+        let csv = "key,en,ko\nhello,Hello,안녕\nbye,Bye,\nhello,Hi,".as_bytes();
+        let parse_with = |policy| {
+            let options = ParseOptions {
+                duplicate_keys: policy,
+                ..Default::default()
+            };
+            parse(csv, &options)
+        };
+
+        let err = parse_with(DuplicateKeyPolicy::Error).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::DuplicateKey);
+        let location = err.location.unwrap();
+        assert_eq!((location.key.as_deref(), location.row), (Some("hello"), Some(4)));
+        assert!(err.message.contains("row 2"));
+
+        let result = parse_with(DuplicateKeyPolicy::KeepFirst).unwrap();
+        assert_eq!(result.data["en"]["hello"], "Hello");
+        assert_eq!(result.data["ko"]["hello"], "안녕");
+        assert!(result.warnings.is_empty());
+
+        // 마지막 행이 통째로 이김: 비어 있는 ko 값이 이전 값을 남기지 않음
+        let result = parse_with(DuplicateKeyPolicy::KeepLast).unwrap();
+        assert_eq!(result.data["en"]["hello"], "Hi");
+        assert!(!result.data["ko"].contains_key("hello"));
+        assert!(result.warnings.is_empty());
+
+        let result = parse_with(DuplicateKeyPolicy::Warn).unwrap();
+        assert_eq!(result.data["en"]["hello"], "Hi");
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, ErrorKind::DuplicateKey);
+    }
+
+    #[test]
+    fn test_parse_report_lists_every_duplicate() {
+        let options = ParseOptions {
+            duplicate_keys: DuplicateKeyPolicy::Error,
+            ..Default::default()
+        };
+        let report = parse_report(b"key,en\na,1\nb,2\na,3\nb,4\na,5", &options);
+
+        let rows: Vec<(Option<&str>, Option<usize>)> = report
+            .errors
+            .iter()
+            .map(|e| {
+                let location = e.location.as_ref().unwrap();
+                (location.key.as_deref(), location.row)
+            })
+            .collect();
+        assert_eq!(rows, vec![(Some("a"), Some(4)), (Some("b"), Some(5)), (Some("a"), Some(6))]);
+    }
+
+    #[test]
+    fn test_nested_key_conflict() {
+        let csv = b"key,en\ncommon.button.submit,Submit\nother,Other\ncommon.button,Button";
+
+        let err = parse(csv, &ParseOptions::default()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NestedKeyConflict);
+        assert!(err
+            .message
+            .contains("'common.button.submit' (row 2) and 'common.button' (row 4)"));
+        let location = err.location.unwrap();
+        assert_eq!((location.key.as_deref(), location.row), (Some("common.button"), Some(4)));
+
+        // flat 출력에서는 충돌이 아님
+        let flat = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
+        assert!(parse(csv, &flat).is_ok());
+
+        let options = ParseOptions {
+            nested_leaf_key: Some("_".to_string()),
+            ..Default::default()
+        };
+        let result = parse(csv, &options).unwrap();
+        assert_eq!(result.data["en"]["common"]["button"]["_"], "Button");
+        assert_eq!(result.data["en"]["common"]["button"]["submit"], "Submit");
+    }
+
+    #[test]
+    fn test_strict_missing_translations() {
+        let csv = b"key,en,ko,ja\nhello,Hello,,Konnichiwa\nbye,Bye,Annyeong,\nko_only,,Hanguk,";
+        let options = ParseOptions {
+            strict_missing: true,
+            ..Default::default()
+        };
+
+        let err = parse(csv, &options).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::MissingTranslation);
+        let location = err.location.unwrap();
+        assert_eq!(
+            (location.key.as_deref(), location.row, location.column, location.column_name.as_deref()),
+            (Some("hello"), Some(2), Some(3), Some("ko"))
+        );
+
+        let report = parse_report(csv, &options);
+        let missing: Vec<(Option<&str>, Option<&str>)> = report
+            .errors
+            .iter()
+            .map(|e| {
+                let location = e.location.as_ref().unwrap();
+                (location.key.as_deref(), location.column_name.as_deref())
+            })
+            .collect();
+        assert_eq!(missing, vec![(Some("hello"), Some("ko")), (Some("bye"), Some("ja"))]);
+
+        // 기준 언어를 바꾸면 ko에만 있는 키가 기준이 됨
+        let options = ParseOptions {
+            strict_missing: true,
+            reference_language: Some("ko".to_string()),
+            ..Default::default()
+        };
+        let report = parse_report(csv, &options);
+        assert_eq!(report.errors.len(), 3);
+
+        assert!(parse(csv, &ParseOptions::default()).is_ok());
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let csv = "key,en\n\
+                   config.count,42\n\
+                   config.ratio,0.5\n\
+                   config.enabled,true\n\
+                   config.code,007\n\
+                   config.flag,'false\n\
+                   config.none,null\n\
+                   tags,\"[\"\"a\\nb\"\", 1]\"\n\
+                   items.0,First\n\
+                   items.1,Second\n\
+                   items.2,Third";
+        let options = ParseOptions {
+            typed_values: true,
+            ..Default::default()
+        };
+        let result = parse(csv.as_bytes(), &options).unwrap();
+        let en = serde_json::to_value(&result.data["en"]).unwrap();
+        assert_eq!(
+            en,
+            serde_json::json!({
+                "config": {
+                    "code": "007",
+                    "count": 42,
+                    "enabled": true,
+                    "flag": "false",
+                    "none": null,
+                    "ratio": 0.5,
+                },
+                "items": ["First", "Second", "Third"],
+                "tags": ["a\nb", 1],
+            })
+        );
+
+        // 기본값은 모두 문자열
+        let result = parse(csv.as_bytes(), &ParseOptions::default()).unwrap();
+        assert_eq!(result.data["en"]["config"]["count"], "42");
+        assert_eq!(result.data["en"]["items"]["0"], "First");
+    }
+
+    #[test]
+    fn test_key_order() {
+        let csv = "key,en\nmenu.item10,Ten\nmenu.item2,Two\ntitle,Title\nmenu.item1,One";
+        let menu_keys = |order: KeyOrder| -> Vec<String> {
+            let options = ParseOptions {
+                key_order: order,
+                ..Default::default()
+            };
+            let result = parse(csv.as_bytes(), &options).unwrap();
+            assert_eq!(result.data["en"].keys().collect::<Vec<_>>(), vec!["menu", "title"]);
+            result.data["en"]["menu"].as_object().unwrap().keys().cloned().collect()
+        };
+
+        assert_eq!(menu_keys(KeyOrder::Alphabetical), vec!["item1", "item10", "item2"]);
+        assert_eq!(menu_keys(KeyOrder::Natural), vec!["item1", "item2", "item10"]);
+        assert_eq!(menu_keys(KeyOrder::Source), vec!["item10", "item2", "item1"]);
+    }
+
+    #[test]
+    fn test_typed_values_round_trip() {
+        use crate::export::{merge_jsons_to_csv, LangJsonInput};
+
+        let json = serde_json::json!({
+            "app": { "max": 10, "beta": false, "steps": ["One", "Two"] },
+            "title": "Hello",
+        });
+        let inputs = vec![LangJsonInput {
+            language: "en".to_string(),
+            content: json.to_string(),
+        }];
+        let csv = merge_jsons_to_csv(&inputs, ".").unwrap();
+
+        let options = ParseOptions {
+            typed_values: true,
+            ..Default::default()
+        };
+        let result = parse(csv.as_bytes(), &options).unwrap();
+        assert_eq!(serde_json::to_value(&result.data["en"]).unwrap(), json);
+    }
+
+    #[test]
+    fn test_typed_values_round_trip_keeps_string_types() {
+        use crate::export::{merge_jsons_to_csv_with_options, LangJsonInput, MergeOptions};
+
+        let json = serde_json::json!({
+            "enabled": true,
+            "flag": "true",
+            "count": 3,
+            "status": "404",
+            "list": "[1, 2]",
+            "none": null,
+            "quoted": "'42",
+            "text": "'hello",
+        });
+        let inputs = vec![LangJsonInput {
+            language: "en".to_string(),
+            content: json.to_string(),
+        }];
+        let merge_options = MergeOptions {
+            typed_values: true,
+            ..Default::default()
+        };
+        let csv = merge_jsons_to_csv_with_options(&inputs, ".", &merge_options).unwrap();
+        assert!(csv.contains("flag,'true\n"));
+        assert!(csv.contains("none,null\n"));
+        assert!(csv.contains("quoted,''42\n"));
+
+        let options = ParseOptions {
+            typed_values: true,
+            ..Default::default()
+        };
+        let result = parse(csv.as_bytes(), &options).unwrap();
+        assert_eq!(serde_json::to_value(&result.data["en"]).unwrap(), json);
+    }
 }
@@ -1,15 +1,17 @@
-use crate::error::{ParseError, Result};
-use crate::parser::validate_header;
-use crate::transform::process_escape_sequences;
-use crate::types::{LocaleData, ParseOptions, ParseResult};
-use calamine::{open_workbook_auto_from_rs, Reader};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::error::{ErrorKind, ParseError, Result, HEADER_ROW};
+use crate::parser::{invalid_separators, validate_header, CollectedRows, RowCollector};
+use crate::types::{ParseOptions, ParseReport, ParseResult, SheetSelection};
+use calamine::{open_workbook_auto_from_rs, Data, Range, Reader, Sheets};
+use std::collections::{BTreeMap, HashMap};
 use std::io::Cursor;
 
-/// Excel 데이터 파싱
-pub fn parse(data: &[u8], options: &ParseOptions) -> Result<ParseResult> {
+/// 메모리 버퍼에서 연 워크북
+type Workbook<'a> = Sheets<Cursor<&'a [u8]>>;
+
+/// 워크북 열기 (시트가 없으면 에러)
+fn open_workbook(data: &[u8]) -> Result<(Workbook<'_>, Vec<String>)> {
     let cursor = Cursor::new(data);
-    let mut workbook = open_workbook_auto_from_rs(cursor)
+    let workbook = open_workbook_auto_from_rs(cursor)
         .map_err(|e| ParseError::excel_open_error(&e.to_string()))?;
 
     let sheet_names = workbook.sheet_names().to_vec();
@@ -17,164 +19,218 @@ pub fn parse(data: &[u8], options: &ParseOptions) -> Result<ParseResult> {
         return Err(ParseError::empty_workbook());
     }
 
-    let range = workbook
-        .worksheet_range(&sheet_names[0])
-        .map_err(|e| ParseError::worksheet_read_error(&sheet_names[0], &e.to_string()))?;
+    Ok((workbook, sheet_names))
+}
 
-    let mut rows = range.rows();
+/// 선택 옵션에 해당하는 시트 이름 목록
+fn resolve_sheets(sheet_names: &[String], sheet: &SheetSelection) -> Result<Vec<String>> {
+    match sheet {
+        SheetSelection::AllAsNamespaces => Ok(sheet_names.to_vec()),
+        SheetSelection::Index(index) => sheet_names
+            .get(*index)
+            .map(|name| vec![name.clone()])
+            .ok_or_else(|| ParseError::sheet_not_found(&format!("#{}", index), sheet_names)),
+        SheetSelection::Name(name) => {
+            if let Some(found) = sheet_names.iter().find(|n| *n == name) {
+                return Ok(vec![found.clone()]);
+            }
+            // 같은 이름이 없으면 숫자를 시트 번호로 취급
+            name.parse::<usize>()
+                .ok()
+                .and_then(|index| sheet_names.get(index))
+                .map(|found| vec![found.clone()])
+                .ok_or_else(|| ParseError::sheet_not_found(name, sheet_names))
+        }
+    }
+}
 
-    // 헤더 읽기
-    let header_row = rows.next().ok_or_else(ParseError::empty_sheet)?;
-    let headers: Vec<String> = header_row
-        .iter()
-        .map(|cell| cell.to_string().trim().to_string())
-        .collect();
+fn read_range(workbook: &mut Workbook<'_>, name: &str) -> Result<Range<Data>> {
+    workbook
+        .worksheet_range(name)
+        .map_err(|e| ParseError::worksheet_read_error(name, &e.to_string()))
+}
+
+/// 워크북의 시트 이름 목록
+pub fn list_sheets(data: &[u8]) -> Result<Vec<String>> {
+    open_workbook(data).map(|(_, names)| names)
+}
+
+/// Excel 데이터 파싱 (첫 번째 시트)
+pub fn parse(data: &[u8], options: &ParseOptions) -> Result<ParseResult> {
+    parse_sheet(data, options, &SheetSelection::default())
+}
 
-    let header_info = validate_header(&headers)?;
+/// 선택한 시트 파싱
+/// `SheetSelection::AllAsNamespaces`이면 모든 시트를 읽고 시트 이름을 키 앞에 붙임
+pub fn parse_sheet(data: &[u8], options: &ParseOptions, sheet: &SheetSelection) -> Result<ParseResult> {
+    collect_sheets(data, options, sheet, false)?.into_result(options)
+}
 
-    // 1단계: 모든 데이터를 flat하게 수집
-    let mut flat_data: HashMap<String, Vec<(String, String)>> = HashMap::new();
-    for lang in &header_info.languages {
-        flat_data.insert(lang.clone(), Vec::new());
+/// 진단 모드 파싱: 첫 에러에서 멈추지 않고 선택한 시트의 모든 문제를 수집
+pub fn parse_report(data: &[u8], options: &ParseOptions, sheet: &SheetSelection) -> ParseReport {
+    match collect_sheets(data, options, sheet, true) {
+        Ok(collected) => collected.into_report(options),
+        Err(e) => ParseReport::failed(e),
     }
+}
 
-    // separator 검증용
-    let mut separators_found: HashSet<char> = HashSet::new();
-    let mut first_offending_row: Option<usize> = None;
-    let expected_sep = options.separator.chars().next().unwrap_or('.');
+fn collect_sheets(
+    data: &[u8],
+    options: &ParseOptions,
+    sheet: &SheetSelection,
+    diagnostic: bool,
+) -> Result<CollectedRows> {
+    let (mut workbook, sheet_names) = open_workbook(data)?;
+
+    let mut ranges = Vec::new();
+    for name in resolve_sheets(&sheet_names, sheet)? {
+        let range = read_range(&mut workbook, &name)?;
+        ranges.push((name, range));
+    }
 
-    let mut row_count = 0;
-    for row in rows {
-        row_count += 1;
+    collect_ranges(ranges, options, *sheet == SheetSelection::AllAsNamespaces, diagnostic)
+}
 
-        let key = match row.get(0) {
-            Some(cell) => cell.to_string().trim().to_string(),
-            None => continue,
-        };
+/// 시트 범위들의 데이터를 합침
+/// `namespaced`이면 빈 시트는 건너뛰고 각 키 앞에 `<시트 이름><구분자>`를 붙임
+fn collect_ranges(
+    ranges: Vec<(String, Range<Data>)>,
+    options: &ParseOptions,
+    namespaced: bool,
+    diagnostic: bool,
+) -> Result<CollectedRows> {
+    let mut merged = CollectedRows {
+        languages: Vec::new(),
+        flat_data: HashMap::new(),
+        metadata: BTreeMap::new(),
+        language_columns: BTreeMap::new(),
+        row_count: 0,
+        errors: Vec::new(),
+        warnings: Vec::new(),
+    };
 
-        if key.is_empty() {
+    for (name, range) in ranges {
+        if namespaced && range.rows().next().is_none() {
             continue;
         }
 
-        // key 안의 구분자 추출
-        for ch in key.chars() {
-            if ch == '.' || ch == '/' || ch == '-' {
-                separators_found.insert(ch);
-                if ch != expected_sep && first_offending_row.is_none() {
-                    first_offending_row = Some(row_count);
+        // 여러 시트를 읽을 때는 어느 시트의 문제인지 메시지에 표시
+        let in_sheet = |mut err: ParseError| {
+            if namespaced {
+                err.message = format!("Sheet '{}': {}", name, err.message);
+            }
+            err
+        };
+
+        // 시트 이름은 키의 첫 구간이 되므로 키와 같은 구분자 검사를 거침
+        if namespaced {
+            let invalid = invalid_separators(&name, options.separator.chars().next().unwrap_or('.'));
+            if !invalid.is_empty() {
+                let err = in_sheet(ParseError::mixed_separators_in_sheet_name(invalid, &options.separator));
+                if !diagnostic {
+                    return Err(err);
                 }
+                merged.errors.push(err);
             }
         }
 
-        for lang in &header_info.languages {
-            let col_idx = header_info.language_indices[lang];
-            let raw_value = row
-                .get(col_idx)
-                .map(|cell| cell.to_string().trim().to_string())
-                .unwrap_or_default();
-
-            if raw_value.is_empty() {
+        let sheet = match collect_range(&range, options, diagnostic) {
+            Ok(sheet) => sheet,
+            Err(e) if diagnostic => {
+                merged.errors.push(in_sheet(e));
                 continue;
             }
+            Err(e) => return Err(in_sheet(e)),
+        };
 
-            let value = if options.process_escapes {
-                process_escape_sequences(&raw_value)
+        merged.row_count += sheet.row_count;
+        merged.errors.extend(sheet.errors.into_iter().map(in_sheet));
+        merged.warnings.extend(sheet.warnings.into_iter().map(in_sheet));
+        // 시트마다 컬럼 위치가 다르면 언어가 처음 나온 시트 기준
+        for (lang, column) in sheet.language_columns {
+            merged.language_columns.entry(lang).or_insert(column);
+        }
+        for lang in sheet.languages {
+            if !merged.languages.contains(&lang) {
+                merged.languages.push(lang);
+            }
+        }
+        for (lang, entries) in sheet.flat_data {
+            let target = merged.flat_data.entry(lang).or_default();
+            for (key, value) in entries {
+                let key = if namespaced {
+                    format!("{}{}{}", name, options.separator, key)
+                } else {
+                    key
+                };
+                target.push((key, value));
+            }
+        }
+        for (key, metadata) in sheet.metadata {
+            let key = if namespaced {
+                format!("{}{}{}", name, options.separator, key)
             } else {
-                raw_value
+                key
             };
-
-            flat_data.get_mut(lang).unwrap().push((key.clone(), value));
+            merged.metadata.insert(key, metadata);
         }
     }
 
-    // 구분자 혼재 여부 체크
-    let invalid: Vec<char> = separators_found
-        .iter()
-        .cloned()
-        .filter(|c| *c != expected_sep)
-        .collect();
-    if !invalid.is_empty() {
-        return Err(ParseError::mixed_separators(
-            invalid,
-            &options.separator,
-            first_offending_row,
-        ));
-    }
-
-    // 2단계: nested 또는 flat으로 변환
-    let mut locale_data = if options.nested {
-        build_nested_locale_data(flat_data, &options.separator)
-    } else {
-        build_flat_locale_data(flat_data)
-    };
-
-    // 알파벳 순 정렬
-    locale_data = crate::transform::sort_locale_data(locale_data);
-
-    Ok(ParseResult {
-        languages: header_info.languages,
-        data: locale_data,
-        row_count,
-    })
-}
-
-/// Flat 데이터를 그대로 LocaleData로 변환
-fn build_flat_locale_data(flat_data: HashMap<String, Vec<(String, String)>>) -> LocaleData {
-    let mut result: LocaleData = BTreeMap::new();
-
-    for (lang, entries) in flat_data {
-        let mut lang_map: BTreeMap<String, serde_json::Value> = BTreeMap::new();
-        for (key, value) in entries {
-            lang_map.insert(key, serde_json::Value::String(value));
-        }
-        result.insert(lang, lang_map);
+    if merged.languages.is_empty() && merged.errors.is_empty() {
+        return Err(ParseError::empty_sheet());
     }
 
-    result
+    Ok(merged)
 }
 
-/// Flat 데이터를 nested 구조로 변환
-fn build_nested_locale_data(
-    flat_data: HashMap<String, Vec<(String, String)>>,
-    separator: &str,
-) -> LocaleData {
-    let mut result: LocaleData = BTreeMap::new();
-
-    for (lang, entries) in flat_data {
-        let mut root: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+/// 시트 하나의 데이터 행 수집
+fn collect_range(range: &Range<Data>, options: &ParseOptions, diagnostic: bool) -> Result<CollectedRows> {
+    let mut rows = range.rows();
 
-        for (key, value) in entries {
-            if key.contains(separator) {
-                insert_nested(&mut root, &key, value, separator);
-            } else {
-                root.insert(key, serde_json::Value::String(value));
-            }
-        }
+    // 헤더 읽기
+    let header_row = rows.next().ok_or_else(ParseError::empty_sheet)?;
+    let headers: Vec<String> = header_row
+        .iter()
+        .map(|cell| cell.to_string().trim().to_string())
+        .collect();
 
-        let mut lang_map: BTreeMap<String, serde_json::Value> = BTreeMap::new();
-        for (k, v) in root {
-            lang_map.insert(k, crate::transform::sort_value(v));
-        }
-        result.insert(lang, lang_map);
+    // 행 번호는 시트의 실제 행 (범위가 1행에서 시작하지 않으면 그만큼 밀림)
+    let header_line = range.start().map_or(HEADER_ROW, |(row, _)| row as usize + 1);
+    let at_header = |e: ParseError| if header_line == HEADER_ROW { e } else { e.at_row(header_line) };
+    let mut header_info = validate_header(&headers).map_err(at_header)?;
+    header_info.warnings = header_info.warnings.into_iter().map(at_header).collect();
+    let mut collector = RowCollector::new(header_info, options);
+
+    for (line, row) in (header_line + 1..).zip(rows) {
+        collector.next_row();
+        collector.push_row(line, |col| {
+            row.get(col)
+                .map(|cell| cell.to_string().trim().to_string())
+                .unwrap_or_default()
+        });
     }
 
-    result
+    Ok(collector.finish(diagnostic))
 }
 
 /// Excel을 CSV 문자열로 변환 (첫 번째 시트 기준)
 pub fn to_csv(data: &[u8]) -> Result<String> {
-    let cursor = Cursor::new(data);
-    let mut workbook = open_workbook_auto_from_rs(cursor)
-        .map_err(|e| ParseError::excel_open_error(&e.to_string()))?;
+    sheet_to_csv(data, &SheetSelection::default())
+}
 
-    let sheet_names = workbook.sheet_names().to_vec();
-    if sheet_names.is_empty() {
-        return Err(ParseError::empty_workbook());
+/// 선택한 시트 하나를 CSV 문자열로 변환
+pub fn sheet_to_csv(data: &[u8], sheet: &SheetSelection) -> Result<String> {
+    if *sheet == SheetSelection::AllAsNamespaces {
+        return Err(ParseError::new(
+            ErrorKind::WorksheetReadError,
+            "CSV conversion needs a single sheet",
+        )
+        .with_suggestion("Select a sheet by name or index"));
     }
 
-    let range = workbook
-        .worksheet_range(&sheet_names[0])
-        .map_err(|e| ParseError::worksheet_read_error(&sheet_names[0], &e.to_string()))?;
+    let (mut workbook, sheet_names) = open_workbook(data)?;
+    let name = resolve_sheets(&sheet_names, sheet)?.remove(0);
+    let range = read_range(&mut workbook, &name)?;
 
     let mut wtr = csv::Writer::from_writer(Vec::new());
 
@@ -196,58 +252,174 @@ pub fn to_csv(data: &[u8]) -> Result<String> {
     String::from_utf8(bytes).map_err(|e| ParseError::utf8_error(&e.utf8_error()))
 }
 
-/// Nested 키를 JSON 구조에 삽입
-fn insert_nested(
-    root: &mut serde_json::Map<String, serde_json::Value>,
-    key: &str,
-    value: String,
-    separator: &str,
-) {
-    let parts: Vec<&str> = key.split(separator).collect();
-    let mut current = root;
-
-    for (i, part) in parts.iter().enumerate() {
-        if i == parts.len() - 1 {
-            current.insert(part.to_string(), serde_json::Value::String(value));
-            return;
-        }
+/// Excel 헤더만 파싱하여 언어 목록 반환 (첫 번째 시트)
+pub fn parse_header_only(data: &[u8]) -> Result<Vec<String>> {
+    parse_sheet_header_only(data, &SheetSelection::default())
+}
 
-        if !current.contains_key(*part) {
-            current.insert(
-                part.to_string(),
-                serde_json::Value::Object(serde_json::Map::new()),
-            );
+/// 선택한 시트의 헤더만 파싱하여 언어 목록 반환
+/// `SheetSelection::AllAsNamespaces`이면 모든 시트의 언어를 합친 목록
+pub fn parse_sheet_header_only(data: &[u8], sheet: &SheetSelection) -> Result<Vec<String>> {
+    let (mut workbook, sheet_names) = open_workbook(data)?;
+    let namespaced = *sheet == SheetSelection::AllAsNamespaces;
+
+    let mut languages: Vec<String> = Vec::new();
+    for name in resolve_sheets(&sheet_names, sheet)? {
+        let range = read_range(&mut workbook, &name)?;
+        let header_row = match range.rows().next() {
+            Some(row) => row,
+            None if namespaced => continue,
+            None => return Err(ParseError::empty_sheet()),
+        };
+        let headers: Vec<String> = header_row
+            .iter()
+            .map(|cell| cell.to_string().trim().to_string())
+            .collect();
+
+        for lang in validate_header(&headers)?.languages {
+            if !languages.contains(&lang) {
+                languages.push(lang);
+            }
         }
+    }
 
-        current = current
-            .get_mut(*part)
-            .and_then(|v| v.as_object_mut())
-            .expect("Expected object for nested key");
+    if languages.is_empty() {
+        return Err(ParseError::empty_sheet());
     }
+    Ok(languages)
 }
 
-/// Excel 헤더만 파싱하여 언어 목록 반환
-pub fn parse_header_only(data: &[u8]) -> Result<Vec<String>> {
-    let cursor = Cursor::new(data);
-    let mut workbook = open_workbook_auto_from_rs(cursor)
-        .map_err(|e| ParseError::excel_open_error(&e.to_string()))?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(rows: &[&[&str]]) -> Range<Data> {
+        let cells = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(r, row)| {
+                row.iter().enumerate().map(move |(c, value)| {
+                    calamine::Cell::new((r as u32, c as u32), Data::String(value.to_string()))
+                })
+            })
+            .collect();
+        Range::from_sparse(cells)
+    }
 
-    let sheet_names = workbook.sheet_names().to_vec();
-    if sheet_names.is_empty() {
-        return Err(ParseError::empty_workbook());
+    fn sheet_names() -> Vec<String> {
+        vec!["common".to_string(), "auth".to_string(), "2024".to_string()]
     }
 
-    let range = workbook
-        .worksheet_range(&sheet_names[0])
-        .map_err(|e| ParseError::worksheet_read_error(&sheet_names[0], &e.to_string()))?;
+    #[test]
+    fn test_resolve_sheets() {
+        let names = sheet_names();
+        assert_eq!(resolve_sheets(&names, &SheetSelection::default()).unwrap(), vec!["common"]);
+        assert_eq!(resolve_sheets(&names, &SheetSelection::Index(1)).unwrap(), vec!["auth"]);
+        assert_eq!(
+            resolve_sheets(&names, &SheetSelection::Name("auth".to_string())).unwrap(),
+            vec!["auth"]
+        );
+        // 같은 이름의 시트가 우선, 없으면 번호로 취급
+        assert_eq!(
+            resolve_sheets(&names, &SheetSelection::from_str_lossy("2024")).unwrap(),
+            vec!["2024"]
+        );
+        assert_eq!(
+            resolve_sheets(&names, &SheetSelection::from_str_lossy("1")).unwrap(),
+            vec!["auth"]
+        );
+        assert_eq!(resolve_sheets(&names, &SheetSelection::AllAsNamespaces).unwrap(), names);
+
+        let err = resolve_sheets(&names, &SheetSelection::Name("checkout".to_string())).unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::SheetNotFound);
+        assert!(err.suggestion.unwrap().contains("common, auth, 2024"));
+        assert!(resolve_sheets(&names, &SheetSelection::Index(5)).is_err());
+    }
 
-    let header_row = range.rows().next().ok_or_else(ParseError::empty_sheet)?;
-    let headers: Vec<String> = header_row
-        .iter()
-        .map(|cell| cell.to_string().trim().to_string())
-        .collect();
+    #[test]
+    fn test_parse_ranges_as_namespaces() {
+        let ranges = vec![
+            (
+                "common".to_string(),
+                range(&[&["key", "en", "ko"], &["hello", "Hello", "안녕"]]),
+            ),
+            ("empty".to_string(), Range::empty()),
+            (
+                "auth".to_string(),
+                range(&[&["key", "en", "ja"], &["login.title", "Log in", "ログイン"]]),
+            ),
+        ];
+
+        let options = ParseOptions::default();
+        let result = collect_ranges(ranges, &options, true, false).unwrap().into_result(&options).unwrap();
+        assert_eq!(result.languages, vec!["en", "ko", "ja"]);
+        assert_eq!(result.row_count, 2);
+
+        let en = result.data.get("en").unwrap();
+        assert_eq!(en["common"]["hello"], "Hello");
+        assert_eq!(en["auth"]["login"]["title"], "Log in");
+        assert!(result.data.get("ko").unwrap().get("auth").is_none());
+    }
 
-    let header_info = validate_header(&headers)?;
-    Ok(header_info.languages)
-}
+    #[test]
+    fn test_parse_ranges_single_sheet() {
+        let ranges = vec![("common".to_string(), range(&[&["key", "en"], &["hello", "Hello"]]))];
+        let options = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
 
+        let result = collect_ranges(ranges, &options, false, false).unwrap().into_result(&options).unwrap();
+        assert_eq!(result.data.get("en").unwrap().get("hello").unwrap(), "Hello");
+    }
+
+    #[test]
+    fn test_sheet_name_with_other_separator() {
+        let ranges = || {
+            vec![
+                ("common".to_string(), range(&[&["key", "en"], &["hello", "Hello"]])),
+                ("user-profile".to_string(), range(&[&["key", "en"], &["name", "Name"]])),
+            ]
+        };
+        let options = ParseOptions::default();
+
+        let Err(err) = collect_ranges(ranges(), &options, true, false) else {
+            panic!("sheet name with '-' should be rejected");
+        };
+        assert_eq!(err.kind, crate::error::ErrorKind::MixedSeparators);
+        assert!(err.message.starts_with("Sheet 'user-profile': "));
+
+        let report = collect_ranges(ranges(), &options, true, true).unwrap().into_report(&options);
+        assert!(report.result.is_none());
+        assert_eq!(report.errors.len(), 1);
+
+        // 한 시트만 읽을 때는 시트 이름이 키에 들어가지 않음
+        let single = vec![("user-profile".to_string(), range(&[&["key", "en"], &["name", "Name"]]))];
+        assert!(collect_ranges(single, &options, false, false).is_ok());
+
+        let dashed = ParseOptions {
+            separator: "-".to_string(),
+            ..Default::default()
+        };
+        let result = collect_ranges(ranges(), &dashed, true, false).unwrap().into_result(&dashed).unwrap();
+        assert_eq!(result.data["en"]["user"]["profile"]["name"], "Name");
+    }
+
+    #[test]
+    fn test_collect_ranges_diagnostic() {
+        let ranges = vec![
+            ("common".to_string(), range(&[&["key", "en"], &["a/b", "x"], &["c-d", "y"]])),
+            ("auth".to_string(), range(&[&["id", "en"], &["login", "Log in"]])),
+        ];
+
+        let report = collect_ranges(ranges, &ParseOptions::default(), true, true)
+            .unwrap()
+            .into_report(&ParseOptions::default());
+        assert!(report.result.is_none());
+        assert_eq!(report.errors.len(), 3);
+        assert_eq!(report.errors[0].kind, crate::error::ErrorKind::MixedSeparators);
+        assert!(report.errors[0].message.starts_with("Sheet 'common': "));
+        assert_eq!(report.errors[2].kind, crate::error::ErrorKind::InvalidKeyColumn);
+        assert!(report.errors[2].message.starts_with("Sheet 'auth': "));
+    }
+}
@@ -0,0 +1,557 @@
+//! iOS `Localizable.strings` / `.stringsdict` / `.xcstrings` (String Catalog) 지원
+//!
+//! - `.strings`: 언어별 `"key" = "value";` 파일 (복수형 키 제외)
+//! - `.stringsdict`: 언어별 복수형 plist (`items_one`, `items_other` -> `items`)
+//! - `.xcstrings`: 모든 언어를 담는 단일 JSON 카탈로그 (가져오기 지원)
+//!
+//! `{{name}}`은 `%1$@`, `{{count}}`는 `%1$d` 형식 지정자로 변환됩니다.
+//! 위치 번호는 모든 언어에서 같도록 원문 언어의 변수 순서를 따릅니다.
+//! `.xcstrings`의 `comment`에는 키 메타데이터의 설명을 기록하고, 가져올 때는 위치 번호로 변수 이름을 복원합니다.
+
+use crate::error::{ParseError, Result};
+use crate::export::{flatten_locale, value_to_cell, TableData};
+use crate::lang_codes::normalize_lang_code;
+use crate::parser::arb::DESCRIPTION_COLUMN;
+use crate::parser::{key_metadata, placeholder_order, plural_quantity, PLURAL_QUANTITIES};
+use crate::transform::{split_variables, StringSegment, VariableType};
+use crate::types::ParseResult;
+use quick_xml::escape::escape;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// 복수형 기본 키 -> [(CLDR 카테고리, 값)]
+type PluralGroups = BTreeMap<String, Vec<(String, String)>>;
+
+/// 한 언어의 flat 데이터를 일반 문자열과 복수형(기본 키 -> [(quantity, 값)])으로 분리
+fn split_plurals(
+    data: &Map<String, Value>,
+    separator: &str,
+) -> (BTreeMap<String, String>, PluralGroups) {
+    let mut strings = BTreeMap::new();
+    let mut plurals = PluralGroups::new();
+
+    for (key, value) in flatten_locale(data, separator) {
+        let text = value_to_cell(&value);
+        if text.is_empty() {
+            continue;
+        }
+        match plural_quantity(&key) {
+            Some((base, quantity)) => plurals.entry(base).or_default().push((quantity, text)),
+            None => {
+                strings.insert(key, text);
+            }
+        }
+    }
+
+    for items in plurals.values_mut() {
+        items.sort_by_key(|(q, _)| PLURAL_QUANTITIES.iter().position(|x| x == q));
+    }
+
+    (strings, plurals)
+}
+
+/// 변수 이름에 맞는 형식 지정자 (`count`는 정수, 나머지는 객체)
+fn format_specifier(position: usize, name: &str) -> String {
+    let conversion = if name == "count" { 'd' } else { '@' };
+    format!("%{}${}", position, conversion)
+}
+
+/// `{{name}}`을 위치 형식 지정자로 변환하고 리터럴 `%`는 `%%`로 escape
+/// `order`는 위치 번호를 정할 변수 순서 (`placeholder_order` 참고)
+fn to_format_string(value: &str, order: &[String]) -> String {
+    let mut out = String::new();
+    let mut positions: Vec<String> = order.to_vec();
+
+    for segment in split_variables(value) {
+        match segment {
+            StringSegment::Variable(var) if var.var_type == VariableType::DoubleBrace => {
+                let name = var.name.unwrap_or_default().trim().to_string();
+                let position = match positions.iter().position(|n| *n == name) {
+                    Some(idx) => idx + 1,
+                    None => {
+                        positions.push(name.clone());
+                        positions.len()
+                    }
+                };
+                out.push_str(&format_specifier(position, &name));
+            }
+            // %s, %d 등 이미 형식 지정자인 변수는 그대로 유지
+            StringSegment::Variable(var) if var.var_type == VariableType::Printf => {
+                out.push_str(&var.full_match);
+            }
+            StringSegment::Variable(var) => out.push_str(&var.full_match.replace('%', "%%")),
+            StringSegment::Text(text) => out.push_str(&text.replace('%', "%%")),
+        }
+    }
+
+    out
+}
+
+/// 위치 형식 지정자(`%1$@`, `%2$lld` 등)이면 (길이, 위치 번호, 정수 변환 여부)
+fn positional_specifier(text: &str) -> Option<(usize, usize, bool)> {
+    let rest = text.strip_prefix('%')?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let position = rest[..digits].parse().ok().filter(|p| *p > 0)?;
+    let rest = rest[digits..].strip_prefix('$')?;
+    let modifiers = rest.find(|c: char| !matches!(c, 'h' | 'l' | 'q' | 'L' | 'z' | 'j' | 't'))?;
+    let integer = match rest[modifiers..].chars().next()? {
+        'd' | 'D' | 'i' | 'u' | 'U' | 'x' | 'X' | 'o' | 'O' => true,
+        '@' | 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A' | 'c' | 'C' | 's' | 'S' | 'p' => false,
+        _ => return None,
+    };
+    Some((digits + modifiers + 3, position, integer))
+}
+
+/// `to_format_string`의 역변환
+/// 위치 형식 지정자는 `name_for(위치 번호, 정수 여부)`의 `{{name}}`으로, `%%`는 `%`로 바꾸고
+/// 위치 번호가 없는 형식 지정자(`%@`, `%lld`)는 그대로 둡니다.
+fn from_format_string(value: &str, name_for: impl Fn(usize, bool) -> String) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(idx) = rest.find('%') {
+        out.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+
+        if let Some(after) = rest.strip_prefix("%%") {
+            out.push('%');
+            rest = after;
+            continue;
+        }
+        match positional_specifier(rest) {
+            Some((len, position, integer)) => {
+                out.push_str(&format!("{{{{{}}}}}", name_for(position, integer)));
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('%');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 위치 번호의 변수 이름
+/// `names`(참조 데이터의 변수 순서)에 없으면 복수형의 정수는 `count`, 나머지는 `arg<위치>`
+fn placeholder_name(names: &[String], position: usize, integer: bool, plural: bool) -> String {
+    match names.get(position - 1) {
+        Some(name) => name.clone(),
+        None if integer && plural => "count".to_string(),
+        None => format!("arg{}", position),
+    }
+}
+
+/// `.strings` 문자열 리터럴 escape
+fn escape_strings_literal(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// 언어 코드를 `.lproj` 디렉토리 이름으로 변환 (예: "pt-BR" -> "pt-BR.lproj")
+pub fn lproj_dir(language: &str) -> String {
+    format!("{}.lproj", language)
+}
+
+// ============================================================================
+// .strings / .stringsdict
+// ============================================================================
+
+/// 한 언어의 `Localizable.strings` 생성 (복수형 키는 `.stringsdict`로 분리)
+pub fn to_strings(result: &ParseResult, language: &str, separator: &str) -> String {
+    let empty = Map::new();
+    let data = result.data.get(language).unwrap_or(&empty);
+    let (strings, _) = split_plurals(data, separator);
+    let order = placeholder_order(result, separator);
+
+    let mut out = String::new();
+    for (key, value) in strings {
+        let value = to_format_string(&value, order.get(&key).map(Vec::as_slice).unwrap_or_default());
+        out.push_str(&format!(
+            "\"{}\" = \"{}\";\n",
+            escape_strings_literal(&key),
+            escape_strings_literal(&value)
+        ));
+    }
+    out
+}
+
+/// 한 언어의 `Localizable.stringsdict` 생성 (복수형 키가 없으면 None)
+///
+/// 각 복수형 키는 `count` 인자 하나로 카테고리를 선택합니다.
+/// 예: `cart.items_one`, `cart.items_other` -> `cart.items` (`%#@count@`)
+pub fn to_stringsdict(result: &ParseResult, language: &str, separator: &str) -> Option<String> {
+    let empty = Map::new();
+    let data = result.data.get(language).unwrap_or(&empty);
+    let (_, plurals) = split_plurals(data, separator);
+    if plurals.is_empty() {
+        return None;
+    }
+    let order = placeholder_order(result, separator);
+
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+<plist version=\"1.0\">\n<dict>\n",
+    );
+    for (base, items) in plurals {
+        let order = order.get(&base).map(Vec::as_slice).unwrap_or_default();
+        let format_key = match order.iter().position(|n| n == "count") {
+            Some(idx) => format!("%{}$#@count@", idx + 1),
+            None => "%#@count@".to_string(),
+        };
+
+        out.push_str(&format!("    <key>{}</key>\n    <dict>\n", escape(base.as_str())));
+        out.push_str("        <key>NSStringLocalizedFormatKey</key>\n");
+        out.push_str(&format!("        <string>{}</string>\n", format_key));
+        out.push_str("        <key>count</key>\n        <dict>\n");
+        out.push_str("            <key>NSStringFormatSpecTypeKey</key>\n");
+        out.push_str("            <string>NSStringPluralRuleType</string>\n");
+        out.push_str("            <key>NSStringFormatValueTypeKey</key>\n");
+        out.push_str("            <string>d</string>\n");
+        for (quantity, value) in items {
+            out.push_str(&format!("            <key>{}</key>\n", quantity));
+            out.push_str(&format!(
+                "            <string>{}</string>\n",
+                escape(to_format_string(&value, order).as_str())
+            ));
+        }
+        out.push_str("        </dict>\n    </dict>\n");
+    }
+    out.push_str("</dict>\n</plist>\n");
+
+    Some(out)
+}
+
+/// 모든 언어의 `.lproj` 파일 생성 (경로 -> 내용)
+/// 예: { "en.lproj/Localizable.strings": ..., "en.lproj/Localizable.stringsdict": ... }
+pub fn to_lproj_files(result: &ParseResult, separator: &str) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    for lang in &result.languages {
+        let dir = lproj_dir(lang);
+        files.insert(
+            format!("{}/Localizable.strings", dir),
+            to_strings(result, lang, separator),
+        );
+        if let Some(dict) = to_stringsdict(result, lang, separator) {
+            files.insert(format!("{}/Localizable.stringsdict", dir), dict);
+        }
+    }
+    files
+}
+
+// ============================================================================
+// .xcstrings (String Catalog)
+// ============================================================================
+
+fn string_unit(value: &str) -> Value {
+    json!({ "stringUnit": { "state": "translated", "value": value } })
+}
+
+/// 모든 언어를 담은 `Localizable.xcstrings` 생성
+///
+/// 키 메타데이터의 설명(`description`)은 `comment`에 기록됩니다.
+///
+/// # Arguments
+/// * `source_language` - 카탈로그의 원문 언어 (`sourceLanguage`)
+/// * `separator` - nested 데이터를 flat 키로 변환할 구분자
+pub fn to_xcstrings(result: &ParseResult, source_language: &str, separator: &str) -> Result<String> {
+    let order = placeholder_order(result, separator);
+    let mut strings: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+
+    for lang in &result.languages {
+        let Some(data) = result.data.get(lang) else { continue };
+        let (singles, plurals) = split_plurals(data, separator);
+
+        for (key, value) in singles {
+            let value = to_format_string(&value, order.get(&key).map(Vec::as_slice).unwrap_or_default());
+            strings.entry(key).or_default().insert(lang.clone(), string_unit(&value));
+        }
+        for (base, items) in plurals {
+            let base_order = order.get(&base).map(Vec::as_slice).unwrap_or_default();
+            let variations: Map<String, Value> = items
+                .into_iter()
+                .map(|(quantity, value)| (quantity, string_unit(&to_format_string(&value, base_order))))
+                .collect();
+            strings
+                .entry(base)
+                .or_default()
+                .insert(lang.clone(), json!({ "variations": { "plural": variations } }));
+        }
+    }
+
+    let strings: Map<String, Value> = strings
+        .into_iter()
+        .map(|(key, localizations)| {
+            let mut entry = Map::new();
+            if let Some(description) = key_metadata(result, &key).and_then(|m| m.description.as_ref()) {
+                entry.insert("comment".to_string(), Value::String(description.clone()));
+            }
+            entry.insert("extractionState".to_string(), Value::String("manual".to_string()));
+            entry.insert("localizations".to_string(), Value::Object(localizations));
+            (key, Value::Object(entry))
+        })
+        .collect();
+
+    let catalog = json!({
+        "sourceLanguage": source_language,
+        "strings": strings,
+        "version": "1.0",
+    });
+    serde_json::to_string_pretty(&catalog).map_err(ParseError::json_serialize_error)
+}
+
+/// `stringUnit.value` 읽기
+fn unit_value(localization: &Value) -> Option<&str> {
+    localization.get("stringUnit")?.get("value")?.as_str()
+}
+
+/// `Localizable.xcstrings`를 `key,lang...,description` 표로 변환 (`export::merge_jsons_to_table`과 같은 형식)
+///
+/// - 원문 언어가 첫 번째 언어 열, 나머지 언어는 코드 순
+/// - 복수형(`variations.plural`)은 `key_one`, `key_other` 행으로 펼침
+/// - 원문 언어 값이 없는 키는 키 자체를 원문으로 사용 (Xcode 기본 동작)
+/// - `comment`는 마지막 `description` 열에 넣음
+///
+/// # Arguments
+/// * `reference` - `%1$@` 등의 위치 번호를 변수 이름으로 복원할 기존 데이터 (없으면 `argN`, 복수형의 정수는 `count`)
+/// * `separator` - `reference`의 nested 데이터를 flat 키로 변환할 구분자
+pub fn parse_xcstrings(data: &str, reference: Option<&ParseResult>, separator: &str) -> Result<TableData> {
+    let catalog: Value =
+        serde_json::from_str(data).map_err(|e| ParseError::json_parse_error("xcstrings", e))?;
+    let source_language = catalog
+        .get("sourceLanguage")
+        .and_then(Value::as_str)
+        .map(normalize_lang_code)
+        .ok_or_else(|| ParseError::xcstrings_parse_error("missing 'sourceLanguage'"))?;
+    let strings = catalog
+        .get("strings")
+        .and_then(Value::as_object)
+        .ok_or_else(|| ParseError::xcstrings_parse_error("missing 'strings' object"))?;
+
+    let order = reference.map(|r| placeholder_order(r, separator)).unwrap_or_default();
+
+    // 키 -> 언어 -> 값
+    let mut cells: BTreeMap<String, HashMap<String, String>> = BTreeMap::new();
+    let mut descriptions: HashMap<String, String> = HashMap::new();
+    let mut other_languages = std::collections::BTreeSet::new();
+
+    for (key, entry) in strings {
+        let names = order.get(key).map(Vec::as_slice).unwrap_or_default();
+        if let Some(comment) = entry.get("comment").and_then(Value::as_str).filter(|c| !c.is_empty()) {
+            descriptions.insert(key.clone(), comment.to_string());
+        }
+        let localizations = entry.get("localizations").and_then(Value::as_object);
+
+        let mut has_source = false;
+        for (lang, localization) in localizations.into_iter().flatten() {
+            let lang = normalize_lang_code(lang);
+            if lang != source_language {
+                other_languages.insert(lang.clone());
+            }
+
+            if let Some(plural) = localization.pointer("/variations/plural").and_then(Value::as_object) {
+                for (quantity, variant) in plural {
+                    let value = unit_value(variant).unwrap_or_default();
+                    cells
+                        .entry(format!("{}_{}", key, quantity))
+                        .or_default()
+                        .insert(lang.clone(), from_format_string(value, |p, int| placeholder_name(names, p, int, true)));
+                }
+                has_source |= lang == source_language;
+            } else if let Some(value) = unit_value(localization) {
+                cells
+                    .entry(key.clone())
+                    .or_default()
+                    .insert(lang.clone(), from_format_string(value, |p, int| placeholder_name(names, p, int, false)));
+                has_source |= lang == source_language;
+            }
+        }
+
+        if !has_source {
+            cells
+                .entry(key.clone())
+                .or_default()
+                .insert(source_language.clone(), from_format_string(key, |p, int| placeholder_name(names, p, int, false)));
+        }
+    }
+
+    let mut languages = vec![source_language];
+    languages.extend(other_languages);
+
+    let rows = cells
+        .into_iter()
+        .map(|(key, mut values)| {
+            let description = descriptions
+                .get(&key)
+                .or_else(|| plural_quantity(&key).and_then(|(base, _)| descriptions.get(&base)))
+                .cloned()
+                .unwrap_or_default();
+            let mut row = Vec::with_capacity(languages.len() + 2);
+            row.push(key);
+            row.extend(languages.iter().map(|lang| values.remove(lang).unwrap_or_default()));
+            row.push(description);
+            row
+        })
+        .collect();
+
+    let mut header = vec!["key".to_string()];
+    header.extend(languages);
+    header.push(DESCRIPTION_COLUMN.to_string());
+
+    Ok(TableData { header, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::types::ParseOptions;
+
+    fn sample_result() -> ParseResult {
+        let csv_str = "key,en,ko\n\
+common.hello,Hello {{name}}!,{{name}}님 안녕하세요!\n\
+common.quote,\"Say \"\"hi\"\" 100%\",\n\
+cart.items_one,{{count}} item for {{name}},{{name}}님 {{count}}개\n\
+cart.items_other,{{count}} items for {{name}},{{name}}님 {{count}}개";
+        crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_to_strings() {
+        let result = sample_result();
+        let en = to_strings(&result, "en", ".");
+        assert_eq!(
+            en,
+            "\"common.hello\" = \"Hello %1$@!\";\n\"common.quote\" = \"Say \\\"hi\\\" 100%%\";\n"
+        );
+
+        let ko = to_strings(&result, "ko", ".");
+        assert_eq!(ko, "\"common.hello\" = \"%1$@님 안녕하세요!\";\n");
+    }
+
+    #[test]
+    fn test_to_stringsdict() {
+        let result = sample_result();
+        let dict = to_stringsdict(&result, "ko", ".").unwrap();
+        assert!(dict.contains("<key>cart.items</key>"));
+        assert!(dict.contains("<string>%1$#@count@</string>"));
+        assert!(dict.contains("<key>other</key>\n            <string>%2$@님 %1$d개</string>"));
+
+        let no_plurals = crate::parser::csv::parse(b"key,en\na,b", &ParseOptions::default()).unwrap();
+        assert!(to_stringsdict(&no_plurals, "en", ".").is_none());
+    }
+
+    #[test]
+    fn test_to_lproj_files() {
+        let files = to_lproj_files(&sample_result(), ".");
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec![
+                "en.lproj/Localizable.strings",
+                "en.lproj/Localizable.stringsdict",
+                "ko.lproj/Localizable.strings",
+                "ko.lproj/Localizable.stringsdict",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xcstrings_round_trip() {
+        let csv_str = "key,en,ko,#description\n\
+common.hello,Hello {{name}}!,{{name}}님 안녕하세요!,Home greeting\n\
+common.quote,\"Say \"\"hi\"\" 100%\",,\n\
+cart.items_one,{{count}} item for {{name}},{{name}}님 {{count}}개,Cart badge\n\
+cart.items_other,{{count}} items for {{name}},{{name}}님 {{count}}개,";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+        let catalog = to_xcstrings(&result, "en", ".").unwrap();
+
+        let value: Value = serde_json::from_str(&catalog).unwrap();
+        assert_eq!(value["sourceLanguage"], "en");
+        assert_eq!(
+            value["strings"]["cart.items"]["localizations"]["en"]["variations"]["plural"]["one"]["stringUnit"]
+                ["value"],
+            "%1$d item for %2$@"
+        );
+        assert_eq!(value["strings"]["cart.items"]["comment"], "Cart badge");
+        assert_eq!(value["strings"]["common.hello"]["comment"], "Home greeting");
+        assert!(value["strings"]["common.quote"].get("comment").is_none());
+
+        let table = parse_xcstrings(&catalog, Some(&result), ".").unwrap();
+        assert_eq!(table.header, vec!["key", "en", "ko", "description"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["cart.items_one", "{{count}} item for {{name}}", "{{name}}님 {{count}}개", "Cart badge"],
+                vec!["cart.items_other", "{{count}} items for {{name}}", "{{name}}님 {{count}}개", "Cart badge"],
+                vec!["common.hello", "Hello {{name}}!", "{{name}}님 안녕하세요!", "Home greeting"],
+                vec!["common.quote", "Say \"hi\" 100%", "", ""],
+            ]
+        );
+
+        // 참조 데이터가 없으면 위치 번호로 이름을 붙임 (번역문에서 어순이 바뀌어도 같은 변수)
+        let table = parse_xcstrings(&catalog, None, ".").unwrap();
+        assert_eq!(table.rows[0][1..3], ["{{count}} item for {{arg2}}", "{{arg2}}님 {{count}}개"]);
+        assert_eq!(table.rows[2][1..3], ["Hello {{arg1}}!", "{{arg1}}님 안녕하세요!"]);
+    }
+
+    #[test]
+    fn test_positional_specifier() {
+        assert_eq!(positional_specifier("%1$@!"), Some((4, 1, false)));
+        assert_eq!(positional_specifier("%2$lld files"), Some((6, 2, true)));
+        assert_eq!(positional_specifier("%12$d"), Some((5, 12, true)));
+        assert_eq!(positional_specifier("%lld"), None);
+        assert_eq!(positional_specifier("%0$@"), None);
+        assert_eq!(positional_specifier("%1$"), None);
+    }
+
+    #[test]
+    fn test_parse_xcstrings_from_xcode() {
+        let catalog = r#"{
+  "sourceLanguage" : "en",
+  "strings" : {
+    "Welcome" : {
+      "comment" : "Shown on the first launch",
+      "localizations" : {
+        "ja" : { "stringUnit" : { "state" : "translated", "value" : "ようこそ" } }
+      }
+    },
+    "%lld files" : {
+      "localizations" : {
+        "de" : { "stringUnit" : { "state" : "needs_review", "value" : "%lld Dateien" } }
+      }
+    }
+  },
+  "version" : "1.0"
+}"#;
+        let table = parse_xcstrings(catalog, None, ".").unwrap();
+        assert_eq!(table.header, vec!["key", "en", "de", "ja", "description"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["%lld files", "%lld files", "%lld Dateien", "", ""],
+                vec!["Welcome", "Welcome", "", "ようこそ", "Shown on the first launch"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_xcstrings_invalid() {
+        let err = parse_xcstrings("not json", None, ".").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::JsonParseError);
+
+        let err = parse_xcstrings(r#"{"strings": {}}"#, None, ".").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::XcstringsParseError);
+    }
+}
@@ -1,11 +1,25 @@
+pub mod android;
+pub mod arb;
 pub mod csv;
 pub mod excel;
+pub mod ios;
+pub mod po;
+pub mod xliff;
 
 use crate::error::{ParseError, Result};
-use crate::lang_codes::{is_known_lang_code, normalize_lang_code, warn_unknown_lang_code};
-use crate::types::HeaderInfo;
+use crate::lang_codes::{is_known_lang_code, log_warning, normalize_lang_code};
+use crate::transform::{extract_variables, get_plural_base_key, get_plural_suffix, VariableType};
+use crate::transform::{index_objects_to_arrays, order_value, parse_typed_value, process_escape_sequences};
+use crate::types::{
+    DuplicateKeyPolicy, HeaderInfo, KeyMetadata, LocaleData, MetadataColumn, ParseOptions, ParseReport, ParseResult,
+};
+use quick_xml::escape::unescape;
+use quick_xml::events::BytesStart;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// 헤더 검증: 첫 번째 컬럼이 "key"이고 나머지가 언어 코드인지 확인
+/// `#description`, `#context`, `#maxLength`, `#tags` 같은 메타데이터 열은 언어로 취급하지 않고,
+/// 알 수 없는 `#` 열은 무시합니다.
 pub fn validate_header(headers: &[String]) -> Result<HeaderInfo> {
     if headers.is_empty() {
         return Err(ParseError::empty_data());
@@ -27,11 +41,33 @@ pub fn validate_header(headers: &[String]) -> Result<HeaderInfo> {
             continue;
         }
 
+        if let Some(column) = MetadataColumn::parse(lang_raw) {
+            // 같은 메타데이터 열이 다시 나오면 첫 번째 컬럼만 사용
+            if !info.metadata_columns.iter().any(|(c, _)| *c == column) {
+                info.metadata_columns.push((column, idx + 1));
+            }
+            continue;
+        }
+        if lang_raw.starts_with('#') {
+            continue;
+        }
+
         // 언어 코드 정규화 및 검증
         let lang_normalized = normalize_lang_code(lang_raw);
 
+        // 같은 언어가 다시 나오면 첫 번째 컬럼만 사용
+        if let Some(first_idx) = info.language_indices.get(&lang_normalized) {
+            info.warnings.push(ParseError::duplicate_language_column(
+                &lang_normalized,
+                first_idx + 1,
+                idx + 2,
+            ));
+            continue;
+        }
+
         if !is_known_lang_code(&lang_normalized) {
-            warn_unknown_lang_code(&lang_normalized);
+            info.warnings
+                .push(ParseError::unknown_language_code(&lang_normalized, idx + 2));
         }
 
         info.language_indices.insert(lang_normalized.clone(), idx + 1);
@@ -46,35 +82,525 @@ pub fn validate_header(headers: &[String]) -> Result<HeaderInfo> {
 }
 
 /// 키를 separator로 분리하여 nested object로 변환
+///
+/// `a`와 `a.b`처럼 한 키가 다른 키의 접두어이면 `leaf_key`가 있을 때만
+/// 값을 `{ "b": ..., "<leaf_key>": <a의 값> }`으로 함께 보관하고, 없으면 `NestedKeyConflict` 에러를 반환합니다.
 pub fn build_nested_value(
     target: &mut serde_json::Map<String, serde_json::Value>,
     key: &str,
-    value: &str,
+    value: impl Into<serde_json::Value>,
     separator: &str,
-) {
+    leaf_key: Option<&str>,
+) -> Result<()> {
     let parts: Vec<&str> = key.split(separator).collect();
+    let (last, parents) = parts.split_last().expect("split always yields one part");
 
-    if parts.len() == 1 {
-        // 단일 키: 직접 삽입
-        target.insert(key.to_string(), serde_json::Value::String(value.to_string()));
-        return;
+    let mut current = target;
+    for (i, part) in parents.iter().enumerate() {
+        let entry = current
+            .entry(part.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+
+        // 중간 파트 자리에 이미 값이 있음: `a` 뒤에 `a.b`
+        if !entry.is_object() {
+            let Some(leaf_key) = leaf_key else {
+                return Err(ParseError::nested_key_conflict(&parts[..=i].join(separator), None, key, None));
+            };
+            let leaf = std::mem::take(entry);
+            *entry = serde_json::Value::Object(serde_json::Map::from_iter([(leaf_key.to_string(), leaf)]));
+        }
+
+        current = entry.as_object_mut().expect("converted to object above");
     }
 
-    // nested 키: 재귀적으로 object 생성
-    let mut current = target;
-    for (i, part) in parts.iter().enumerate() {
-        if i == parts.len() - 1 {
-            // 마지막 파트: 값 삽입
-            current.insert(part.to_string(), serde_json::Value::String(value.to_string()));
+    let value = value.into();
+    match current.get_mut(*last) {
+        // 마지막 파트 자리에 이미 object가 있음: `a.b` 뒤에 `a`
+        Some(serde_json::Value::Object(children)) => match leaf_key {
+            Some(leaf_key) => {
+                children.insert(leaf_key.to_string(), value);
+            }
+            None => {
+                let nested = first_leaf_path(children, separator);
+                return Err(ParseError::nested_key_conflict(
+                    &format!("{}{}{}", key, separator, nested),
+                    None,
+                    key,
+                    None,
+                ));
+            }
+        },
+        _ => {
+            current.insert(last.to_string(), value);
+        }
+    }
+
+    Ok(())
+}
+
+/// object 안에서 처음 만나는 값까지의 경로 (충돌 메시지용)
+fn first_leaf_path(map: &serde_json::Map<String, serde_json::Value>, separator: &str) -> String {
+    match map.iter().next() {
+        Some((k, serde_json::Value::Object(children))) if !children.is_empty() => {
+            format!("{}{}{}", k, separator, first_leaf_path(children, separator))
+        }
+        Some((k, _)) => k.clone(),
+        None => String::new(),
+    }
+}
+
+/// (키, 값) 목록을 옵션에 따라 nested 또는 flat 언어별 맵으로 변환 (키는 `key_order` 순서)
+/// (`typed_values`이면 숫자/불리언/null/배열을 복원하고, nested 모드에서는 `items.0` 형태의 키를 배열로 변환)
+pub(crate) fn build_lang_map(
+    entries: Vec<(String, String)>,
+    options: &ParseOptions,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let mut root = serde_json::Map::new();
+    for (key, value) in entries {
+        let value = if options.typed_values {
+            parse_typed_value(&value).unwrap_or(serde_json::Value::String(value))
         } else {
-            // 중간 파트: object 생성 또는 기존 object 사용
-            current = current
-                .entry(part.to_string())
-                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
-                .as_object_mut()
-                .expect("Expected object for nested key");
+            serde_json::Value::String(value)
+        };
+        if options.nested {
+            build_nested_value(&mut root, &key, value, &options.separator, options.nested_leaf_key.as_deref())?;
+        } else {
+            root.insert(key, value);
         }
     }
+
+    if options.typed_values && options.nested {
+        root = root
+            .into_iter()
+            .map(|(k, v)| (k, index_objects_to_arrays(v)))
+            .collect();
+    }
+    match order_value(serde_json::Value::Object(root), options.key_order) {
+        serde_json::Value::Object(map) => Ok(map),
+        _ => unreachable!("order_value keeps objects"),
+    }
+}
+
+/// 언어별 flat 데이터를 옵션에 따라 nested 또는 flat LocaleData로 변환
+pub(crate) fn build_locale_data(
+    flat_data: HashMap<String, Vec<(String, String)>>,
+    options: &ParseOptions,
+) -> Result<LocaleData> {
+    flat_data
+        .into_iter()
+        .map(|(lang, entries)| Ok((lang, build_lang_map(entries, options)?)))
+        .collect()
+}
+
+/// CSV/Excel 데이터 행을 언어별 flat 데이터로 모으는 공통 수집기
+///
+/// 에러를 만나도 멈추지 않고 `errors`에 모아 두므로,
+/// 일반 파싱은 첫 번째 에러를, 진단 모드(`ParseReport`)는 전체 목록을 사용합니다.
+pub(crate) struct RowCollector<'a> {
+    options: &'a ParseOptions,
+    header: HeaderInfo,
+    expected_sep: char,
+    flat_data: HashMap<String, Vec<(String, String)>>,
+    /// (행 번호, 행에서 발견된 잘못된 구분자)
+    separator_rows: Vec<(usize, Vec<char>)>,
+    /// 키 -> 처음 나온 행 번호 (중복 검사용)
+    key_rows: HashMap<String, usize>,
+    /// 상위 경로 -> (그 경로를 처음 사용한 키, 행 번호) (nested 충돌 검사용)
+    prefix_rows: HashMap<String, (String, usize)>,
+    metadata: BTreeMap<String, KeyMetadata>,
+    errors: Vec<ParseError>,
+    warnings: Vec<ParseError>,
+    row_count: usize,
+}
+
+/// `RowCollector::finish` 결과
+pub(crate) struct CollectedRows {
+    pub languages: Vec<String>,
+    pub flat_data: HashMap<String, Vec<(String, String)>>,
+    pub metadata: BTreeMap<String, KeyMetadata>,
+    pub language_columns: BTreeMap<String, usize>,
+    pub row_count: usize,
+    pub errors: Vec<ParseError>,
+    pub warnings: Vec<ParseError>,
+}
+
+impl<'a> RowCollector<'a> {
+    pub(crate) fn new(header: HeaderInfo, options: &'a ParseOptions) -> Self {
+        let flat_data = header
+            .languages
+            .iter()
+            .map(|lang| (lang.clone(), Vec::new()))
+            .collect();
+
+        Self {
+            options,
+            header,
+            expected_sep: options.separator.chars().next().unwrap_or('.'),
+            flat_data,
+            separator_rows: Vec::new(),
+            key_rows: HashMap::new(),
+            prefix_rows: HashMap::new(),
+            metadata: BTreeMap::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            row_count: 0,
+        }
+    }
+
+    /// 데이터 행 하나를 셈 (`row_count`)
+    pub(crate) fn next_row(&mut self) {
+        self.row_count += 1;
+    }
+
+    /// 행 단위 에러 기록
+    pub(crate) fn add_error(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+
+    /// 데이터 행 하나 추가 (`row`는 파일/시트의 실제 줄 번호, `cell(column)`은 해당 컬럼의 trim된 값)
+    pub(crate) fn push_row(&mut self, row: usize, cell: impl Fn(usize) -> String) {
+        let key = cell(0);
+        if key.is_empty() {
+            return;
+        }
+
+        // key 안의 구분자 추출
+        let invalid = invalid_separators(&key, self.expected_sep);
+        if !invalid.is_empty() {
+            self.separator_rows.push((row, invalid));
+        }
+
+        if let Some(&first_row) = self.key_rows.get(&key) {
+            let duplicate = ParseError::duplicate_key(&key, first_row, row);
+            match self.options.duplicate_keys {
+                DuplicateKeyPolicy::Error => {
+                    self.errors.push(duplicate);
+                    return;
+                }
+                DuplicateKeyPolicy::KeepFirst => return,
+                DuplicateKeyPolicy::KeepLast => {}
+                DuplicateKeyPolicy::Warn => self.warnings.push(duplicate),
+            }
+            // 이전 행의 값은 모든 언어에서 제거 (빈 셀이 이전 값을 남기지 않도록)
+            for entries in self.flat_data.values_mut() {
+                entries.retain(|(k, _)| k != &key);
+            }
+        } else {
+            if let Some(conflict) = self.nested_conflict(&key, row) {
+                self.errors.push(conflict);
+                return;
+            }
+            self.key_rows.insert(key.clone(), row);
+        }
+
+        // 중복 키를 덮어쓰는 경우 메타데이터도 마지막 행 기준
+        let metadata = self.row_metadata(&key, row, &cell);
+        if metadata.is_empty() {
+            self.metadata.remove(&key);
+        } else {
+            self.metadata.insert(key.clone(), metadata);
+        }
+
+        for lang in &self.header.languages {
+            let raw_value = cell(self.header.language_indices[lang]);
+            if raw_value.is_empty() {
+                continue;
+            }
+
+            // 타입이 있는 값(JSON 배열 등)은 escape를 JSON 파서가 처리
+            let typed = self.options.typed_values
+                && matches!(parse_typed_value(&raw_value), Some(v) if !v.is_string());
+            let value = if self.options.process_escapes && !typed {
+                process_escape_sequences(&raw_value)
+            } else {
+                raw_value
+            };
+
+            self.flat_data.get_mut(lang).unwrap().push((key.clone(), value));
+        }
+    }
+
+    /// 행의 메타데이터 열 읽기 (숫자가 아닌 `#maxLength`는 경고 후 무시)
+    fn row_metadata(&mut self, key: &str, row: usize, cell: &impl Fn(usize) -> String) -> KeyMetadata {
+        let mut metadata = KeyMetadata::default();
+        for &(column, index) in &self.header.metadata_columns {
+            let value = cell(index);
+            if value.is_empty() {
+                continue;
+            }
+            match column {
+                MetadataColumn::Description => metadata.description = Some(value),
+                MetadataColumn::Context => metadata.context = Some(value),
+                MetadataColumn::MaxLength => match value.parse::<usize>() {
+                    Ok(max) if max > 0 => metadata.max_length = Some(max),
+                    _ => self.warnings.push(ParseError::invalid_metadata_value(
+                        key,
+                        column.name(),
+                        &value,
+                        row,
+                        index + 1,
+                    )),
+                },
+                MetadataColumn::Tags => {
+                    metadata.tags = value
+                        .split([',', ';'])
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                }
+            }
+        }
+        metadata
+    }
+
+    /// `a`와 `a.b`처럼 nested 변환 시 같은 자리를 차지하는 키 검사
+    /// (leaf 키가 설정되어 있으면 둘 다 보관할 수 있으므로 검사하지 않음)
+    fn nested_conflict(&mut self, key: &str, row: usize) -> Option<ParseError> {
+        if !self.options.nested || self.options.nested_leaf_key.is_some() {
+            return None;
+        }
+
+        let separator = self.options.separator.as_str();
+        let prefixes: Vec<&str> = key
+            .match_indices(separator)
+            .map(|(idx, _)| &key[..idx])
+            .collect();
+
+        // 상위 경로가 이미 값으로 쓰임: `a` 뒤에 `a.b`
+        for prefix in &prefixes {
+            if let Some(&first_row) = self.key_rows.get(*prefix) {
+                return Some(ParseError::nested_key_conflict(prefix, Some(first_row), key, Some(row)));
+            }
+        }
+
+        // 이 키가 이미 상위 경로로 쓰임: `a.b` 뒤에 `a`
+        if let Some((nested_key, first_row)) = self.prefix_rows.get(key) {
+            return Some(ParseError::nested_key_conflict(nested_key, Some(*first_row), key, Some(row)));
+        }
+
+        for prefix in prefixes {
+            self.prefix_rows
+                .entry(prefix.to_string())
+                .or_insert_with(|| (key.to_string(), row));
+        }
+        None
+    }
+
+    /// strict 모드: 기준 언어에는 값이 있는데 비어 있는 셀을 에러로 기록
+    fn check_missing(&mut self) {
+        let reference = self
+            .options
+            .reference_language
+            .as_deref()
+            .map(normalize_lang_code)
+            .filter(|lang| self.header.language_indices.contains_key(lang))
+            .or_else(|| self.header.languages.first().cloned());
+        let Some(reference) = reference else {
+            return;
+        };
+
+        let present: HashMap<&str, HashSet<&str>> = self
+            .flat_data
+            .iter()
+            .map(|(lang, entries)| (lang.as_str(), entries.iter().map(|(k, _)| k.as_str()).collect()))
+            .collect();
+
+        let mut reference_keys: Vec<(&str, usize)> = present[reference.as_str()]
+            .iter()
+            .map(|key| (*key, self.key_rows[*key]))
+            .collect();
+        reference_keys.sort_by_key(|(_, row)| *row);
+
+        let mut missing = Vec::new();
+        for (key, row) in reference_keys {
+            for lang in self.header.languages.iter().filter(|l| **l != reference) {
+                let has = |k: &str| present[lang.as_str()].contains(k);
+                if !has(key) && !has_plural_sibling(key, has) {
+                    let column = self.header.language_indices[lang] + 1;
+                    missing.push(ParseError::missing_translation(key, lang, Some(row), Some(column)));
+                }
+            }
+        }
+        self.errors.extend(missing);
+    }
+
+    /// 수집 종료
+    ///
+    /// 구분자 혼재는 `diagnostic`이면 문제 행마다, 아니면 첫 행 기준 하나의 에러로 보고합니다.
+    pub(crate) fn finish(mut self, diagnostic: bool) -> CollectedRows {
+        if diagnostic {
+            for (row, invalid) in &self.separator_rows {
+                self.errors.push(ParseError::mixed_separators(
+                    invalid.clone(),
+                    &self.options.separator,
+                    Some(*row),
+                ));
+            }
+        } else if let Some((first_row, _)) = self.separator_rows.first() {
+            let invalid: Vec<char> = self
+                .separator_rows
+                .iter()
+                .flat_map(|(_, chars)| chars.iter().copied())
+                .collect();
+            self.errors.push(ParseError::mixed_separators(
+                invalid,
+                &self.options.separator,
+                Some(*first_row),
+            ));
+        }
+
+        if self.options.strict_missing {
+            self.check_missing();
+        }
+
+        CollectedRows {
+            language_columns: self
+                .header
+                .language_indices
+                .iter()
+                .map(|(lang, idx)| (lang.clone(), idx + 1))
+                .collect(),
+            languages: self.header.languages,
+            flat_data: self.flat_data,
+            metadata: self.metadata,
+            row_count: self.row_count,
+            errors: self.errors,
+            warnings: self.header.warnings.into_iter().chain(self.warnings).collect(),
+        }
+    }
+}
+
+impl CollectedRows {
+    /// 일반 파싱: 첫 번째 에러가 있으면 반환
+    pub(crate) fn into_result(self, options: &ParseOptions) -> Result<ParseResult> {
+        if let Some(error) = self.errors.into_iter().next() {
+            return Err(error);
+        }
+
+        if options.log_warnings {
+            self.warnings.iter().for_each(log_warning);
+        }
+
+        Ok(ParseResult {
+            languages: self.languages,
+            data: build_locale_data(self.flat_data, options)?,
+            row_count: self.row_count,
+            warnings: self.warnings,
+            metadata: self.metadata,
+            language_columns: self.language_columns,
+        })
+    }
+
+    /// 진단 모드: 모든 에러와 경고를 담은 리포트
+    pub(crate) fn into_report(mut self, options: &ParseOptions) -> ParseReport {
+        let errors = std::mem::take(&mut self.errors);
+        let warnings = self.warnings.clone();
+        let result = if errors.is_empty() {
+            self.into_result(options).ok()
+        } else {
+            None
+        };
+
+        ParseReport {
+            result,
+            errors,
+            warnings,
+        }
+    }
+}
+
+/// `text`에 들어 있는 `expected` 외의 키 구분자 (`.`, `/`, `-`)
+pub(crate) fn invalid_separators(text: &str, expected: char) -> Vec<char> {
+    text.chars()
+        .filter(|ch| matches!(ch, '.' | '/' | '-') && *ch != expected)
+        .collect()
+}
+
+/// CLDR 복수형 카테고리 (Android `<plurals>`, iOS `.stringsdict` 공통)
+pub(crate) const PLURAL_QUANTITIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+/// 복수형 키를 (기본 키, CLDR 카테고리)로 분리
+/// `_plural`은 `other`로 취급하고, `_0` 같은 숫자 접미사는 복수형으로 보지 않음
+pub(crate) fn plural_quantity(key: &str) -> Option<(String, String)> {
+    let quantity = match get_plural_suffix(key)?.as_str() {
+        "plural" => "other".to_string(),
+        q if PLURAL_QUANTITIES.contains(&q) => q.to_string(),
+        _ => return None,
+    };
+    Some((get_plural_base_key(key)?, quantity))
+}
+
+/// 복수형 키의 다른 형태가 있는지 확인 (`has`: 키 존재 여부)
+/// 언어마다 필요한 복수형 카테고리가 다르므로, 같은 기본 키의 다른 형태가 있으면 누락으로 보지 않습니다.
+/// 예: en의 `item_one`은 ja에 `item_other`만 있어도 번역된 것으로 취급
+pub(crate) fn has_plural_sibling(key: &str, has: impl Fn(&str) -> bool) -> bool {
+    let Some((base, _)) = plural_quantity(key) else {
+        return false;
+    };
+    PLURAL_QUANTITIES
+        .iter()
+        .copied()
+        .chain(["plural"])
+        .any(|q| has(&format!("{}_{}", base, q)))
+}
+
+/// 키별 `{{name}}` 변수 순서 (위치 인자 `%1$s`, `%2$s` 번호 결정용)
+///
+/// `languages` 순서(보통 원문 언어가 먼저)대로 처음 등장한 순서를 사용하므로
+/// 번역문에서 어순이 바뀌어도 같은 변수는 같은 번호를 받습니다.
+/// 복수형 키(`items_one`, `items_other`)는 기본 키(`items`)에도 함께 기록됩니다.
+pub(crate) fn placeholder_order(result: &ParseResult, separator: &str) -> HashMap<String, Vec<String>> {
+    let mut order: HashMap<String, Vec<String>> = HashMap::new();
+    for lang in &result.languages {
+        let Some(data) = result.data.get(lang) else { continue };
+        for (key, value) in crate::export::flatten_locale(data, separator) {
+            let Some(text) = value.as_str() else { continue };
+            let names: Vec<String> = extract_variables(text)
+                .into_iter()
+                .filter(|v| v.var_type == VariableType::DoubleBrace)
+                .filter_map(|v| v.name.map(|n| n.trim().to_string()))
+                .collect();
+
+            let base = get_plural_base_key(&key);
+            for target in std::iter::once(key).chain(base) {
+                let entry = order.entry(target).or_default();
+                for name in &names {
+                    if !entry.contains(name) {
+                        entry.push(name.clone());
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+/// 키의 메타데이터
+/// 복수형 기본 키(`items`)에 메타데이터가 없으면 `items_one` 등 카테고리 키의 메타데이터를 사용
+pub(crate) fn key_metadata<'a>(result: &'a ParseResult, key: &str) -> Option<&'a KeyMetadata> {
+    result.metadata.get(key).or_else(|| {
+        PLURAL_QUANTITIES
+            .iter()
+            .find_map(|quantity| result.metadata.get(&format!("{}_{}", key, quantity)))
+    })
+}
+
+/// XML 속성 값 읽기 (네임스페이스 prefix 무시, escape 해제)
+pub(crate) fn xml_attribute(e: &BytesStart, name: &str) -> Option<String> {
+    e.attributes()
+        .with_checks(false)
+        .flatten()
+        .find(|a| a.key.local_name().as_ref() == name.as_bytes())
+        .and_then(|a| {
+            let raw = std::str::from_utf8(&a.value).ok()?;
+            unescape(raw).ok().map(|v| v.into_owned())
+        })
+}
+
+/// XML 엔티티/문자 참조 이름(`amp`, `#x41` 등)을 실제 문자열로 변환
+pub(crate) fn resolve_xml_entity(name: &str) -> Option<String> {
+    unescape(&format!("&{};", name)).ok().map(|v| v.into_owned())
 }
 
 #[cfg(test)]
@@ -115,7 +641,7 @@ mod tests {
     #[test]
     fn test_build_nested_value() {
         let mut map = serde_json::Map::new();
-        build_nested_value(&mut map, "common.button.submit", "Submit", ".");
+        build_nested_value(&mut map, "common.button.submit", "Submit", ".", None).unwrap();
 
         let common = map.get("common").unwrap().as_object().unwrap();
         let button = common.get("button").unwrap().as_object().unwrap();
@@ -123,5 +649,35 @@ mod tests {
 
         assert_eq!(submit, "Submit");
     }
-}
 
+    #[test]
+    fn test_build_nested_value_conflict() {
+        // 값 뒤에 같은 경로의 하위 키
+        let mut map = serde_json::Map::new();
+        build_nested_value(&mut map, "common.button", "Button", ".", None).unwrap();
+        let err = build_nested_value(&mut map, "common.button.submit", "Submit", ".", None).unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::NestedKeyConflict);
+        assert!(err.message.contains("'common.button' and 'common.button.submit'"));
+
+        // 하위 키 뒤에 상위 경로의 값 (조용히 덮어쓰지 않음)
+        let mut map = serde_json::Map::new();
+        build_nested_value(&mut map, "common.button.submit", "Submit", ".", None).unwrap();
+        let err = build_nested_value(&mut map, "common.button", "Button", ".", None).unwrap_err();
+        assert!(err.message.contains("'common.button.submit' and 'common.button'"));
+        assert_eq!(map["common"]["button"]["submit"], "Submit");
+    }
+
+    #[test]
+    fn test_build_nested_value_leaf_key() {
+        for order in [["a.b", "a.b.c"], ["a.b.c", "a.b"]] {
+            let mut map = serde_json::Map::new();
+            for key in order {
+                build_nested_value(&mut map, key, key, ".", Some("$value")).unwrap();
+            }
+            assert_eq!(
+                serde_json::Value::Object(map),
+                serde_json::json!({ "a": { "b": { "$value": "a.b", "c": "a.b.c" } } })
+            );
+        }
+    }
+}
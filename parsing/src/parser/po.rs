@@ -0,0 +1,856 @@
+//! Gettext PO/POT 가져오기/내보내기
+//!
+//! PO 파일은 언어 하나당 파일 하나입니다. 가져올 때는 `msgid`를 키로 사용하고,
+//! 내보낼 때도 키를 `msgid`로 쓰는 key-based 카탈로그를 생성하므로 round-trip이 가능합니다.
+//!
+//! - `msgctxt`: i18next context 규칙에 따라 `{msgid}_{msgctxt}` 키로 변환
+//! - `msgid_plural` / `msgstr[n]`: 언어의 복수형 규칙에 따라 `_one` / `_few` / `_other` 등 CLDR 복수형 키로 변환
+//!   (내보낼 때도 언어마다 자신의 `Plural-Forms`와 `msgstr[n]` 개수를 사용)
+//! - 번역자 주석(`# ...`)은 키 메타데이터의 description으로 가져옴
+//! - `#, fuzzy` 항목은 gettext와 동일하게 번역으로 취급하지 않음
+//! - `#~` (obsolete) 항목은 무시
+//! - 내보낼 때 키 메타데이터(`#description`, `#context` 등 열)는 `#.` 추출 주석으로 기록
+
+use crate::error::{ErrorKind, ParseError, Result};
+use crate::export::{flatten_locale, value_to_cell};
+use crate::lang_codes::normalize_lang_code;
+use crate::parser::{build_lang_map, plural_quantity, PLURAL_QUANTITIES};
+use crate::transform::{get_plural_base_key, is_plural_key};
+use crate::types::{KeyMetadata, LocaleData, ParseOptions, ParseResult};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// PO 항목 하나 (msgid 블록)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoEntry {
+    /// `msgctxt`
+    pub context: Option<String>,
+    /// `msgid`
+    pub msgid: String,
+    /// `msgid_plural`
+    pub msgid_plural: Option<String>,
+    /// `msgstr` (단수형은 1개, 복수형은 `msgstr[n]` 순서대로)
+    pub msgstr: Vec<String>,
+    /// 번역자 주석 (`# ...`)
+    pub translator_comments: Vec<String>,
+    /// 추출 주석 (`#. ...`)
+    pub extracted_comments: Vec<String>,
+    /// 소스 참조 (`#: ...`)
+    pub references: Vec<String>,
+    /// 플래그 (`#, fuzzy, python-format`)
+    pub flags: Vec<String>,
+    /// 항목이 시작되는 줄 번호 (1-based)
+    pub line: usize,
+}
+
+impl PoEntry {
+    /// `fuzzy` 플래그 여부
+    pub fn is_fuzzy(&self) -> bool {
+        self.flags.iter().any(|f| f == "fuzzy")
+    }
+
+    /// 헤더 항목 (`msgid ""`) 여부
+    pub fn is_header(&self) -> bool {
+        self.msgid.is_empty() && self.context.is_none()
+    }
+}
+
+/// 파싱된 PO 카탈로그
+#[derive(Debug, Clone, Default)]
+pub struct PoCatalog {
+    /// 헤더의 `Language` 값 (정규화됨)
+    pub language: Option<String>,
+    /// 헤더 필드 (`Name: value`) 목록
+    pub headers: Vec<(String, String)>,
+    /// 헤더를 제외한 항목들
+    pub entries: Vec<PoEntry>,
+}
+
+/// 마지막으로 읽은 키워드 (continuation 문자열을 어디에 붙일지 결정)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    None,
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+/// PO 텍스트를 카탈로그로 파싱
+pub fn parse_catalog(data: &[u8]) -> Result<PoCatalog> {
+    let text = std::str::from_utf8(data)?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut catalog = PoCatalog::default();
+    let mut entry = PoEntry::default();
+    let mut field = Field::None;
+    let mut has_msgid = false;
+
+    for (idx, raw_line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw_line.trim();
+
+        if line.is_empty() {
+            flush_entry(&mut catalog, &mut entry, &mut has_msgid);
+            field = Field::None;
+            continue;
+        }
+
+        if line.starts_with("#~") {
+            // obsolete 항목
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            // msgstr 뒤에 빈 줄 없이 주석이 오면 새 항목 시작
+            if matches!(field, Field::Str(_)) {
+                flush_entry(&mut catalog, &mut entry, &mut has_msgid);
+            }
+            field = Field::None;
+
+            if let Some(flags) = comment.strip_prefix(',') {
+                entry.flags.extend(
+                    flags
+                        .split(',')
+                        .map(|f| f.trim().to_string())
+                        .filter(|f| !f.is_empty()),
+                );
+            } else if let Some(c) = comment.strip_prefix('.') {
+                entry.extracted_comments.push(c.trim().to_string());
+            } else if let Some(r) = comment.strip_prefix(':') {
+                entry.references.push(r.trim().to_string());
+            } else if comment.starts_with('|') {
+                // previous msgid는 사용하지 않음
+            } else {
+                entry
+                    .translator_comments
+                    .push(comment.strip_prefix(' ').unwrap_or(comment).to_string());
+            }
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let value = unquote(line, line_no)?;
+            match field {
+                Field::Context => entry.context.get_or_insert_with(String::new).push_str(&value),
+                Field::Id => entry.msgid.push_str(&value),
+                Field::IdPlural => entry
+                    .msgid_plural
+                    .get_or_insert_with(String::new)
+                    .push_str(&value),
+                Field::Str(n) => entry.msgstr[n].push_str(&value),
+                Field::None => {
+                    return Err(ParseError::po_parse_error(
+                        line_no,
+                        "string continuation without a preceding keyword",
+                    ));
+                }
+            }
+            continue;
+        }
+
+        let (keyword, rest) = line
+            .split_once(|c: char| c.is_whitespace())
+            .ok_or_else(|| ParseError::po_parse_error(line_no, "expected a keyword and a value"))?;
+        let value = unquote(rest.trim(), line_no)?;
+
+        // 빈 줄 없이 다음 항목이 시작되는 경우
+        if (keyword == "msgctxt" || keyword == "msgid") && matches!(field, Field::Str(_)) {
+            flush_entry(&mut catalog, &mut entry, &mut has_msgid);
+        }
+
+        field = match keyword {
+            "msgctxt" => {
+                entry.context = Some(value);
+                Field::Context
+            }
+            "msgid" => {
+                if entry.line == 0 {
+                    entry.line = line_no;
+                }
+                entry.msgid = value;
+                has_msgid = true;
+                Field::Id
+            }
+            "msgid_plural" => {
+                entry.msgid_plural = Some(value);
+                Field::IdPlural
+            }
+            "msgstr" => {
+                entry.msgstr = vec![value];
+                Field::Str(0)
+            }
+            _ => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        ParseError::po_parse_error(line_no, &format!("unknown keyword '{}'", keyword))
+                    })?;
+                if entry.msgstr.len() <= index {
+                    entry.msgstr.resize(index + 1, String::new());
+                }
+                entry.msgstr[index] = value;
+                Field::Str(index)
+            }
+        };
+
+        if !has_msgid && matches!(field, Field::IdPlural | Field::Str(_)) {
+            return Err(ParseError::po_parse_error(
+                line_no,
+                &format!("'{}' appears before 'msgid'", keyword),
+            ));
+        }
+    }
+
+    flush_entry(&mut catalog, &mut entry, &mut has_msgid);
+    Ok(catalog)
+}
+
+/// 완성된 항목을 카탈로그에 추가 (헤더 항목은 헤더 필드로 해석)
+fn flush_entry(catalog: &mut PoCatalog, entry: &mut PoEntry, has_msgid: &mut bool) {
+    let done = std::mem::take(entry);
+    if !*has_msgid {
+        return;
+    }
+    *has_msgid = false;
+
+    if done.is_header() {
+        let header_text = done.msgstr.first().cloned().unwrap_or_default();
+        for line in header_text.lines() {
+            if let Some((name, value)) = line.split_once(':') {
+                let (name, value) = (name.trim().to_string(), value.trim().to_string());
+                if name.eq_ignore_ascii_case("Language") && !value.is_empty() {
+                    catalog.language = Some(normalize_lang_code(&value));
+                }
+                catalog.headers.push((name, value));
+            }
+        }
+        return;
+    }
+
+    catalog.entries.push(done);
+}
+
+/// `"..."` 문자열의 따옴표 제거 및 escape 해제
+fn unquote(s: &str, line_no: usize) -> Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| ParseError::po_parse_error(line_no, "expected a double-quoted string"))?;
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('"') => result.push('"'),
+            Some('\\') => result.push('\\'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => return Err(ParseError::po_parse_error(line_no, "dangling backslash")),
+        }
+    }
+
+    Ok(result)
+}
+
+/// 언어의 gettext 복수형: `msgstr[n]` 순서의 CLDR 카테고리와 `Plural-Forms`의 plural 식
+struct PluralForms {
+    categories: &'static [&'static str],
+    expression: &'static str,
+}
+
+/// 언어 코드(기본 subtag)로 복수형 규칙 결정 (모르는 언어는 영어와 같은 one/other)
+fn plural_forms(language: &str) -> PluralForms {
+    let primary = language.split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+    let (categories, expression): (&'static [&'static str], &'static str) = match primary.as_str() {
+        "ja" | "ko" | "zh" | "vi" | "th" | "id" | "ms" | "lo" | "km" | "my" => (&["other"], "0"),
+        "fr" => (&["one", "other"], "(n > 1)"),
+        "ru" | "uk" | "be" | "sr" | "hr" | "bs" => (
+            &["one", "few", "many"],
+            "(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2)",
+        ),
+        "pl" => (
+            &["one", "few", "many"],
+            "(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2)",
+        ),
+        "cs" | "sk" => (&["one", "few", "other"], "(n==1 ? 0 : n>=2 && n<=4 ? 1 : 2)"),
+        "sl" => (
+            &["one", "two", "few", "other"],
+            "(n%100==1 ? 0 : n%100==2 ? 1 : n%100==3 || n%100==4 ? 2 : 3)",
+        ),
+        "ar" => (
+            &["zero", "one", "two", "few", "many", "other"],
+            "(n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5)",
+        ),
+        _ => (&["one", "other"], "(n != 1)"),
+    };
+    PluralForms { categories, expression }
+}
+
+/// `msgstr[n]` 개수에 대응하는 접미사 목록
+/// 언어의 복수형 규칙과 개수가 같으면 그 카테고리를, 아니면 개수로 추정
+/// (1개: other, 2개: one/other, 3개: one/few/many, 4개: one/few/many/other,
+/// 5개: one/two/few/many/other, 6개: zero..other), 그 이상은 인덱스 (`_0`, `_1`, ...)
+fn plural_suffixes(language: &str, count: usize) -> Vec<String> {
+    let forms = plural_forms(language);
+    let categories: &[&str] = match count {
+        n if n == forms.categories.len() => forms.categories,
+        1 => &["other"],
+        2 => &["one", "other"],
+        3 => &["one", "few", "many"],
+        4 => &["one", "few", "many", "other"],
+        5 => &["one", "two", "few", "many", "other"],
+        6 => PLURAL_QUANTITIES,
+        n => return (0..n).map(|i| i.to_string()).collect(),
+    };
+    categories.iter().map(|s| s.to_string()).collect()
+}
+
+/// 항목의 기본 키 (`msgid` + context)
+fn entry_base_key(entry: &PoEntry) -> String {
+    let base = if entry.msgid_plural.is_some() && is_plural_key(&entry.msgid) {
+        get_plural_base_key(&entry.msgid).unwrap_or_else(|| entry.msgid.clone())
+    } else {
+        entry.msgid.clone()
+    };
+
+    match &entry.context {
+        Some(ctx) if !ctx.is_empty() => format!("{}_{}", base, ctx),
+        _ => base,
+    }
+}
+
+/// PO 파일을 ParseResult로 변환
+///
+/// # Arguments
+/// * `language` - 번역 언어 (None이면 헤더의 `Language` 사용)
+/// * `source_language` - 지정 시 `msgid`/`msgid_plural`을 해당 언어의 값으로도 채움
+///   (msgid가 원문인 Django 스타일 카탈로그용)
+pub fn parse(
+    data: &[u8],
+    language: Option<&str>,
+    source_language: Option<&str>,
+    options: &ParseOptions,
+) -> Result<ParseResult> {
+    let catalog = parse_catalog(data)?;
+
+    let language = match language.filter(|l| !l.trim().is_empty()) {
+        Some(lang) => normalize_lang_code(lang.trim()),
+        None => catalog.language.clone().ok_or_else(|| {
+            ParseError::new(ErrorKind::NoLanguageColumns, "PO file has no 'Language' header")
+                .with_suggestion("Add a 'Language' header to the PO file or pass the language explicitly")
+        })?,
+    };
+    let source_language = source_language
+        .map(|l| normalize_lang_code(l.trim()))
+        .filter(|l| !l.is_empty() && *l != language);
+
+    let mut languages = Vec::new();
+    if let Some(src) = &source_language {
+        languages.push(src.clone());
+    }
+    languages.push(language.clone());
+
+    let mut flat: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    for lang in &languages {
+        flat.insert(lang.clone(), Vec::new());
+    }
+
+    let mut metadata: BTreeMap<String, KeyMetadata> = BTreeMap::new();
+    let mut row_count = 0;
+    for entry in &catalog.entries {
+        row_count += 1;
+        let base = entry_base_key(entry);
+        // 이 항목에서 만든 키 (번역자 주석을 붙일 대상)
+        let mut keys = Vec::new();
+
+        if let Some(plural) = &entry.msgid_plural {
+            // 원문은 msgid/msgid_plural 두 형태이므로 번역의 형태 수와 관계없이 one/other로 채움
+            if let Some(src) = &source_language {
+                let target = flat.get_mut(src).unwrap();
+                for (suffix, value) in [("one", &entry.msgid), ("other", plural)] {
+                    let key = format!("{}_{}", base, suffix);
+                    target.push((key.clone(), value.clone()));
+                    keys.push(key);
+                }
+            }
+
+            if !entry.is_fuzzy() {
+                let target = flat.get_mut(&language).unwrap();
+                for (value, suffix) in entry.msgstr.iter().zip(plural_suffixes(&language, entry.msgstr.len())) {
+                    if !value.is_empty() {
+                        let key = format!("{}_{}", base, suffix);
+                        target.push((key.clone(), value.clone()));
+                        keys.push(key);
+                    }
+                }
+            }
+        } else {
+            if let Some(src) = &source_language {
+                flat.get_mut(src).unwrap().push((base.clone(), entry.msgid.clone()));
+                keys.push(base.clone());
+            }
+
+            let value = entry.msgstr.first().cloned().unwrap_or_default();
+            if !entry.is_fuzzy() && !value.is_empty() {
+                flat.get_mut(&language).unwrap().push((base.clone(), value));
+                keys.push(base);
+            }
+        }
+
+        // 번역자 주석 (`# ...`)은 시트의 `#description` 열처럼 키 메타데이터로
+        if !entry.translator_comments.is_empty() {
+            let description = entry.translator_comments.join("\n");
+            for key in keys {
+                metadata.entry(key).or_default().description = Some(description.clone());
+            }
+        }
+    }
+
+    let data: LocaleData = flat
+        .into_iter()
+        .map(|(lang, entries)| Ok((lang, build_lang_map(entries, options)?)))
+        .collect::<Result<_>>()?;
+
+    Ok(ParseResult {
+        languages,
+        data,
+        row_count,
+        warnings: Vec::new(),
+        metadata,
+        language_columns: BTreeMap::new(),
+    })
+}
+
+// ============================================================================
+// 내보내기
+// ============================================================================
+
+/// 내보낼 메시지 (단수 또는 복수형 그룹)
+enum Message {
+    Singular(String),
+    /// 기본 키와 모든 언어에 있는 복수형 키 (CLDR 순서)
+    Plural(String, Vec<String>),
+}
+
+/// 모든 언어의 키를 모아 메시지 목록 구성 (CLDR 복수형 키는 기본 키 기준으로 묶음)
+/// `step_1`, `step_2`처럼 숫자 접미사만 있는 키는 복수형으로 보지 않음
+fn collect_messages(flat: &BTreeMap<String, BTreeMap<String, serde_json::Value>>) -> Vec<Message> {
+    let all_keys: BTreeSet<&String> = flat.values().flat_map(|m| m.keys()).collect();
+
+    let mut plurals: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    let mut messages = Vec::new();
+    for key in all_keys {
+        match plural_quantity(key) {
+            Some((base, quantity)) => plurals.entry(base).or_default().push((quantity, key.clone())),
+            None => messages.push((key.clone(), Message::Singular(key.clone()))),
+        }
+    }
+
+    for (base, mut forms) in plurals {
+        forms.sort_by_key(|(quantity, _)| PLURAL_QUANTITIES.iter().position(|q| q == quantity));
+        let keys = forms.into_iter().map(|(_, key)| key).collect();
+        messages.push((base.clone(), Message::Plural(base, keys)));
+    }
+
+    messages.sort_by(|a, b| a.0.cmp(&b.0));
+    messages.into_iter().map(|(_, m)| m).collect()
+}
+
+/// PO 문자열 escape
+fn escape_po(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+}
+
+/// `keyword "value"` 형식으로 쓰기 (여러 줄 값은 줄 단위로 나눔)
+fn write_field(out: &mut String, keyword: &str, value: &str) {
+    let body = value.trim_end_matches('\n');
+    if !body.contains('\n') {
+        out.push_str(&format!(
+            "{} \"{}\"\n",
+            keyword,
+            escape_po(value).replace('\n', "\\n")
+        ));
+        return;
+    }
+
+    out.push_str(&format!("{} \"\"\n", keyword));
+    for line in value.split_inclusive('\n') {
+        out.push_str(&format!("\"{}\"\n", escape_po(line).replace('\n', "\\n")));
+    }
+}
+
+/// 키 메타데이터를 `#.` 추출 주석으로 쓰기 (설명, context, 최대 길이, 태그 순)
+fn write_metadata_comments(out: &mut String, metadata: Option<&KeyMetadata>) {
+    let Some(metadata) = metadata else {
+        return;
+    };
+    if let Some(description) = &metadata.description {
+        for line in description.lines() {
+            out.push_str(&format!("#. {}\n", line));
+        }
+    }
+    if let Some(context) = &metadata.context {
+        out.push_str(&format!("#. Context: {}\n", context.replace('\n', "\\n")));
+    }
+    if let Some(max_length) = metadata.max_length {
+        out.push_str(&format!("#. Max length: {}\n", max_length));
+    }
+    if !metadata.tags.is_empty() {
+        out.push_str(&format!("#. Tags: {}\n", metadata.tags.join(", ")));
+    }
+}
+
+/// 헤더 항목 쓰기
+fn write_header(out: &mut String, language: Option<&str>, forms: &PluralForms) {
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str("\"MIME-Version: 1.0\\n\"\n");
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    out.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+    if let Some(lang) = language {
+        // gettext는 pt_BR 형식 사용
+        out.push_str(&format!("\"Language: {}\\n\"\n", lang.replace('-', "_")));
+    }
+    out.push_str(&format!(
+        "\"Plural-Forms: nplurals={}; plural={};\\n\"\n",
+        forms.categories.len(),
+        forms.expression
+    ));
+    out.push_str("\"X-Generator: LocalizeKit\\n\"\n");
+}
+
+/// PO/POT 텍스트 생성 (`language`가 None이면 POT 템플릿)
+fn write_catalog(
+    result: &ParseResult,
+    language: Option<&str>,
+    source_language: Option<&str>,
+    separator: &str,
+) -> String {
+    let flat: BTreeMap<String, BTreeMap<String, serde_json::Value>> = result
+        .data
+        .iter()
+        .map(|(lang, data)| (lang.clone(), flatten_locale(data, separator)))
+        .collect();
+
+    let lookup = |lang: Option<&str>, key: &str| -> String {
+        lang.and_then(|l| flat.get(l))
+            .and_then(|m| m.get(key))
+            .map(value_to_cell)
+            .unwrap_or_default()
+    };
+
+    let messages = collect_messages(&flat);
+    // POT 템플릿은 영어와 같은 one/other 두 형태
+    let forms = plural_forms(language.unwrap_or_default());
+
+    let mut out = String::new();
+    write_header(&mut out, language, &forms);
+
+    // 원문 주석은 번역 대상 언어와 원문 언어가 다를 때만 출력
+    let source = source_language.filter(|src| Some(*src) != language);
+
+    for message in messages {
+        out.push('\n');
+        match message {
+            Message::Singular(key) => {
+                write_metadata_comments(&mut out, result.metadata.get(&key));
+                let source_text = lookup(source, &key);
+                if !source_text.is_empty() {
+                    out.push_str(&format!("#. {}\n", source_text.replace('\n', "\\n")));
+                }
+                write_field(&mut out, "msgid", &key);
+                write_field(&mut out, "msgstr", &lookup(language, &key));
+            }
+            Message::Plural(base, keys) => {
+                write_metadata_comments(&mut out, keys.iter().find_map(|key| result.metadata.get(key)));
+                for key in &keys {
+                    let source_text = lookup(source, key);
+                    if !source_text.is_empty() {
+                        out.push_str(&format!("#. {}: {}\n", key, source_text.replace('\n', "\\n")));
+                    }
+                }
+                write_field(&mut out, "msgid", &keys[0]);
+                write_field(&mut out, "msgid_plural", &keys[keys.len() - 1]);
+                for (i, category) in forms.categories.iter().enumerate() {
+                    let key = format!("{}_{}", base, category);
+                    write_field(&mut out, &format!("msgstr[{}]", i), &lookup(language, &key));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// 한 언어의 PO 파일 생성
+///
+/// 키는 `msgid`로, 해당 언어의 값은 `msgstr`로 씁니다.
+/// `source_language`가 주어지면 원문을, 키 메타데이터가 있으면 그 내용을 `#.` 추출 주석으로 추가합니다.
+pub fn to_po(
+    result: &ParseResult,
+    language: &str,
+    source_language: Option<&str>,
+    separator: &str,
+) -> String {
+    write_catalog(result, Some(language), source_language, separator)
+}
+
+/// 번역이 비어 있는 POT 템플릿 생성
+pub fn to_pot(result: &ParseResult, source_language: Option<&str>, separator: &str) -> String {
+    write_catalog(result, None, source_language, separator)
+}
+
+/// `ParseResult.languages`의 각 언어별 PO 파일 생성 (언어 코드 -> PO 텍스트)
+pub fn to_po_files(
+    result: &ParseResult,
+    source_language: Option<&str>,
+    separator: &str,
+) -> BTreeMap<String, String> {
+    result
+        .languages
+        .iter()
+        .map(|lang| (lang.clone(), to_po(result, lang, source_language, separator)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PO: &str = r#"# Korean translation
+msgid ""
+msgstr ""
+"Language: ko\n"
+"Plural-Forms: nplurals=2; plural=(n != 1);\n"
+
+# 버튼 라벨
+#. Shown on the login page
+#: templates/login.html:12
+msgid "auth.login"
+msgstr "로그인"
+
+#, fuzzy
+msgid "auth.logout"
+msgstr "로그아웃?"
+
+msgctxt "male"
+msgid "friend"
+msgstr "남자친구"
+
+msgid "cart.items_one"
+msgid_plural "cart.items_other"
+msgstr[0] "{{count}}개"
+msgstr[1] "{{count}}개들"
+
+msgid "multi"
+msgstr ""
+"첫 줄\n"
+"둘째 줄"
+
+#~ msgid "old"
+#~ msgstr "옛날"
+"#;
+
+    #[test]
+    fn test_parse_catalog() {
+        let catalog = parse_catalog(SAMPLE_PO.as_bytes()).unwrap();
+
+        assert_eq!(catalog.language.as_deref(), Some("ko"));
+        assert_eq!(catalog.entries.len(), 5);
+
+        let login = &catalog.entries[0];
+        assert_eq!(login.msgid, "auth.login");
+        assert_eq!(login.msgstr, vec!["로그인"]);
+        assert_eq!(login.translator_comments, vec!["버튼 라벨"]);
+        assert_eq!(login.extracted_comments, vec!["Shown on the login page"]);
+        assert_eq!(login.references, vec!["templates/login.html:12"]);
+
+        assert!(catalog.entries[1].is_fuzzy());
+        assert_eq!(catalog.entries[2].context.as_deref(), Some("male"));
+        assert_eq!(catalog.entries[3].msgstr.len(), 2);
+        assert_eq!(catalog.entries[4].msgstr[0], "첫 줄\n둘째 줄");
+    }
+
+    #[test]
+    fn test_parse_to_locale_data() {
+        let options = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
+        let result = parse(SAMPLE_PO.as_bytes(), None, None, &options).unwrap();
+
+        assert_eq!(result.languages, vec!["ko"]);
+        let ko = result.data.get("ko").unwrap();
+        assert_eq!(ko.get("auth.login").unwrap(), "로그인");
+        assert!(ko.get("auth.logout").is_none(), "fuzzy entries are skipped");
+        assert_eq!(ko.get("friend_male").unwrap(), "남자친구");
+        assert_eq!(ko.get("cart.items_one").unwrap(), "{{count}}개");
+        assert_eq!(ko.get("cart.items_other").unwrap(), "{{count}}개들");
+
+        // 번역자 주석은 키 메타데이터의 description으로
+        assert_eq!(result.metadata["auth.login"].description.as_deref(), Some("버튼 라벨"));
+        assert!(!result.metadata.contains_key("friend_male"));
+    }
+
+    #[test]
+    fn test_parse_with_source_language() {
+        let po = "msgid \"\"\nmsgstr \"Language: de\\n\"\n\nmsgid \"Hello\"\nmsgstr \"Hallo\"\n\nmsgid \"One file\"\nmsgid_plural \"%d files\"\nmsgstr[0] \"Eine Datei\"\nmsgstr[1] \"%d Dateien\"\n";
+        let options = ParseOptions {
+            nested: false,
+            ..Default::default()
+        };
+        let result = parse(po.as_bytes(), None, Some("en"), &options).unwrap();
+
+        assert_eq!(result.languages, vec!["en", "de"]);
+        let en = result.data.get("en").unwrap();
+        assert_eq!(en.get("Hello").unwrap(), "Hello");
+        assert_eq!(en.get("One file_other").unwrap(), "%d files");
+        let de = result.data.get("de").unwrap();
+        assert_eq!(de.get("One file_one").unwrap(), "Eine Datei");
+    }
+
+    #[test]
+    fn test_parse_requires_language() {
+        let err = parse(b"msgid \"a\"\nmsgstr \"b\"\n", None, None, &ParseOptions::default())
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NoLanguageColumns);
+    }
+
+    #[test]
+    fn test_parse_syntax_error() {
+        let err = parse_catalog(b"msgid \"a\"\nmsgstr b\n").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::PoParseError);
+        assert!(err.message.contains("line 2"));
+    }
+
+    #[test]
+    fn test_export_and_round_trip() {
+        let csv_str = "key,en,ko\ncommon.hello,Hello,안녕\ncommon.bye,\"Bye\nnow\",\ncart.items_one,{{count}} item,\ncart.items_other,{{count}} items,{{count}}개";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let files = to_po_files(&result, Some("en"), ".");
+        let ko = files.get("ko").unwrap();
+        assert!(ko.contains("\"Language: ko\\n\""));
+        assert!(ko.contains("#. Hello\nmsgid \"common.hello\"\nmsgstr \"안녕\""));
+        assert!(ko.contains("msgid \"common.bye\"\nmsgstr \"\""));
+        assert!(ko.contains("msgid \"cart.items_one\"\nmsgid_plural \"cart.items_other\""));
+
+        let en = files.get("en").unwrap();
+        assert!(en.contains("msgstr \"\"\n\"Bye\\n\"\n\"now\"\n"));
+        assert!(!en.contains("#. Hello"));
+
+        let back = parse(ko.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("ko"), result.data.get("ko"));
+
+        let back_en = parse(en.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back_en.data.get("en"), result.data.get("en"));
+    }
+
+    #[test]
+    fn test_export_metadata_comments() {
+        let csv_str = "key,en,ko,#description,#context,#maxLength,#tags\n\
+                       greeting,Hello,안녕,\"Home greeting\nShown once\",Header,20,home\n\
+                       cart.items_one,{{count}} item,,Cart badge,,,\n\
+                       cart.items_other,{{count}} items,{{count}}개,,,,";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let ko = to_po(&result, "ko", Some("en"), ".");
+        assert!(ko.contains(
+            "#. Home greeting\n#. Shown once\n#. Context: Header\n#. Max length: 20\n#. Tags: home\n#. Hello\nmsgid \"greeting\""
+        ));
+        assert!(ko.contains("#. Cart badge\n#. cart.items_one: {{count}} item\n"));
+
+        let back = parse(ko.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("ko"), result.data.get("ko"));
+    }
+
+    #[test]
+    fn test_single_plural_form_round_trip() {
+        let csv_str = "key,en,ko\ncart.items_one,{{count}} item,\ncart.items_other,{{count}} items,{{count}}개";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        // ko는 자신의 복수형 규칙(nplurals=1)으로 내보냄
+        let ko = to_po(&result, "ko", None, ".");
+        assert!(ko.contains("\"Plural-Forms: nplurals=1; plural=0;\\n\""));
+        assert!(ko.contains("msgstr[0] \"{{count}}개\"\n"));
+        assert!(!ko.contains("msgstr[1]"));
+        let en = to_po(&result, "en", None, ".");
+        assert!(en.contains("nplurals=2; plural=(n != 1);"));
+
+        // 한 형태만 있는 카탈로그(nplurals=1)는 `_other`로 읽음
+        let single = "msgid \"\"\nmsgstr \"\"\n\"Language: ko\\n\"\n\"Plural-Forms: nplurals=1; plural=0;\\n\"\n\n\
+                      msgid \"cart.items_other\"\nmsgid_plural \"cart.items_other\"\nmsgstr[0] \"{{count}}개\"\n";
+        let back = parse(single.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data["ko"]["cart"]["items_other"], "{{count}}개");
+        assert!(back.data["ko"]["cart"].get("items_one").is_none());
+
+        let ko_only = crate::parser::csv::parse("key,ko\ncart.items_other,{{count}}개".as_bytes(), &ParseOptions::default()).unwrap();
+        let exported = to_po(&ko_only, "ko", None, ".");
+        assert!(exported.contains("nplurals=1"));
+        let back = parse(exported.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("ko"), ko_only.data.get("ko"));
+    }
+
+    #[test]
+    fn test_russian_plural_forms_round_trip() {
+        let csv_str = "key,en,ru\n\
+                       files_one,{{count}} file,{{count}} файл\n\
+                       files_few,,{{count}} файла\n\
+                       files_many,,{{count}} файлов\n\
+                       files_other,{{count}} files,";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let ru = to_po(&result, "ru", None, ".");
+        assert!(ru.contains("\"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 :"));
+        assert!(ru.contains(
+            "msgid \"files_one\"\nmsgid_plural \"files_other\"\n\
+             msgstr[0] \"{{count}} файл\"\nmsgstr[1] \"{{count}} файла\"\nmsgstr[2] \"{{count}} файлов\"\n"
+        ));
+
+        let back = parse(ru.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("ru"), result.data.get("ru"));
+
+        // 언어 규칙과 형태 수가 다르면 개수로 추정 (4개: one/few/many/other)
+        let four = "msgid \"\"\nmsgstr \"Language: uk\\n\"\n\n\
+                    msgid \"a_one\"\nmsgid_plural \"a_other\"\nmsgstr[0] \"1\"\nmsgstr[1] \"2\"\nmsgstr[2] \"5\"\nmsgstr[3] \"1.5\"\n";
+        let back = parse(four.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        let keys: Vec<&String> = back.data["uk"].keys().collect();
+        assert_eq!(keys, vec!["a_few", "a_many", "a_one", "a_other"]);
+    }
+
+    #[test]
+    fn test_numbered_keys_are_not_plurals() {
+        let csv_str = "key,en\nonboarding.step_1,Welcome\nonboarding.step_2,Done";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let en = to_po(&result, "en", None, ".");
+        assert!(!en.contains("msgid_plural"));
+        assert!(en.contains("msgid \"onboarding.step_1\"\nmsgstr \"Welcome\""));
+
+        let back = parse(en.as_bytes(), None, None, &ParseOptions::default()).unwrap();
+        assert_eq!(back.data.get("en"), result.data.get("en"));
+    }
+
+    #[test]
+    fn test_export_pot() {
+        let csv_str = "key,en,ko\ngreeting,Hello,안녕";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let pot = to_pot(&result, Some("en"), ".");
+        assert!(!pot.contains("Language:"));
+        assert!(pot.contains("#. Hello\nmsgid \"greeting\"\nmsgstr \"\""));
+    }
+}
@@ -0,0 +1,760 @@
+//! XLIFF 1.2 / 2.0 가져오기/내보내기
+//!
+//! 번역 에이전시용으로 원문 언어 + 대상 언어 하나씩 XLIFF 파일을 만들고,
+//! 번역되어 돌아온 XLIFF를 다시 `LocaleData`에 병합합니다.
+//!
+//! - 키는 `trans-unit` / `unit`의 `id`로 사용
+//! - 빈 셀은 `state` 속성으로 표시 (1.2: `needs-translation`, 2.0: `initial`)
+//! - 병합할 때는 완료된 상태의 target만 사용하고, 2.0 unit의 여러 segment는 이어 붙임
+//! - `transform::extract_variables`로 찾은 플레이스홀더는 `<ph>` 요소로 보호
+//! - `<note>`는 읽을 때 보존하고, 쓸 때 다시 출력할 수 있음
+//! - 키 메타데이터는 `<note>`로 (1.2의 최대 길이는 `maxwidth` 속성으로) 내보냄
+
+use crate::error::{ParseError, Result};
+use crate::export::{flatten_locale, value_to_cell};
+use crate::lang_codes::normalize_lang_code;
+use crate::parser::{build_lang_map, resolve_xml_entity, xml_attribute};
+use crate::transform::{split_variables, StringSegment};
+use crate::types::{KeyMetadata, ParseOptions, ParseResult};
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// XLIFF 버전
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum XliffVersion {
+    #[default]
+    #[serde(rename = "1.2")]
+    V1_2,
+    #[serde(rename = "2.0")]
+    V2_0,
+}
+
+impl XliffVersion {
+    /// "2.0" / "2"는 2.0, 그 외는 1.2
+    pub fn from_str_lossy(s: &str) -> Self {
+        match s.trim() {
+            "2.0" | "2" => XliffVersion::V2_0,
+            _ => XliffVersion::V1_2,
+        }
+    }
+}
+
+/// 번역 단위 하나
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct XliffUnit {
+    /// 키 (`id` 속성)
+    pub id: String,
+    /// 원문 (`<ph>`는 원래 플레이스홀더 텍스트로 복원됨)
+    pub source: String,
+    /// 번역문 (`<target>`이 없으면 None)
+    pub target: Option<String>,
+    /// `state` 속성 (1.2: target, 2.0: segment)
+    pub state: Option<String>,
+    /// `<note>` 목록
+    pub notes: Vec<String>,
+}
+
+/// 파싱된 XLIFF 문서
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct XliffDocument {
+    pub version: XliffVersion,
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub units: Vec<XliffUnit>,
+}
+
+impl XliffDocument {
+    /// 키별 note 목록 (note가 있는 unit만)
+    pub fn notes(&self) -> BTreeMap<String, Vec<String>> {
+        self.units
+            .iter()
+            .filter(|u| !u.notes.is_empty())
+            .map(|u| (u.id.clone(), u.notes.clone()))
+            .collect()
+    }
+}
+
+/// XLIFF 내보내기 옵션
+#[derive(Debug, Clone)]
+pub struct XliffExportOptions {
+    pub version: XliffVersion,
+    /// 원문 언어
+    pub source_language: String,
+    /// 키 구분자 (nested 데이터를 flat 키로 변환할 때 사용)
+    pub separator: String,
+    /// 키별 note (이전에 읽은 XLIFF의 note를 다시 쓰는 용도)
+    pub notes: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for XliffExportOptions {
+    fn default() -> Self {
+        Self {
+            version: XliffVersion::V1_2,
+            source_language: "en".to_string(),
+            separator: ".".to_string(),
+            notes: BTreeMap::new(),
+        }
+    }
+}
+
+// ============================================================================
+// 내보내기
+// ============================================================================
+
+/// source/target 내용 생성 (플레이스홀더는 `<ph>`로 감쌈)
+fn inline_content(value: &str, version: XliffVersion) -> String {
+    let mut out = String::new();
+    let mut ph_id = 0;
+    for segment in split_variables(value) {
+        let var = match segment {
+            StringSegment::Text(text) => {
+                out.push_str(&escape(text.as_str()));
+                continue;
+            }
+            StringSegment::Variable(var) => var,
+        };
+        ph_id += 1;
+        let code = escape(var.full_match.as_str());
+        match version {
+            XliffVersion::V1_2 => out.push_str(&format!(
+                "<ph id=\"{}\" equiv-text=\"{}\">{}</ph>",
+                ph_id, code, code
+            )),
+            XliffVersion::V2_0 => out.push_str(&format!(
+                "<ph id=\"ph{}\" disp=\"{}\" equiv=\"{}\"/>",
+                ph_id, code, code
+            )),
+        }
+    }
+    out
+}
+
+/// unit에 쓸 note 목록 (note 종류, 내용)
+/// 키 메타데이터 note 다음에 `extra`를 붙이고, 메타데이터와 같은 내용의 `extra`는 생략합니다.
+/// (1.2의 최대 길이는 `maxwidth` 속성으로 쓰므로 2.0에서만 note로 추가)
+fn unit_notes(
+    metadata: Option<&KeyMetadata>,
+    extra: Option<&Vec<String>>,
+    version: XliffVersion,
+) -> Vec<(Option<&'static str>, String)> {
+    let mut notes = Vec::new();
+    if let Some(metadata) = metadata {
+        notes.extend(metadata.description.clone().map(|d| (Some("description"), d)));
+        notes.extend(metadata.context.clone().map(|c| (Some("context"), c)));
+        if version == XliffVersion::V2_0 {
+            notes.extend(metadata.max_length.map(|max| (Some("maxLength"), max.to_string())));
+        }
+        if !metadata.tags.is_empty() {
+            notes.push((Some("tags"), metadata.tags.join(", ")));
+        }
+    }
+    for note in extra.into_iter().flatten() {
+        if !notes.iter().any(|(_, text)| text == note) {
+            notes.push((None, note.clone()));
+        }
+    }
+    notes
+}
+
+/// `<note>` 요소 (종류는 1.2: `from`, 2.0: `category` 속성)
+fn note_element(kind: Option<&str>, text: &str, version: XliffVersion) -> String {
+    let attribute = match (kind, version) {
+        (None, _) => String::new(),
+        (Some(kind), XliffVersion::V1_2) => format!(" from=\"{}\"", kind),
+        (Some(kind), XliffVersion::V2_0) => format!(" category=\"{}\"", kind),
+    };
+    format!("<note{}>{}</note>", attribute, escape(text))
+}
+
+/// 대상 언어 하나에 대한 XLIFF 문서 생성
+pub fn to_xliff(result: &ParseResult, target_language: &str, options: &XliffExportOptions) -> String {
+    let flatten = |lang: &str| {
+        result
+            .data
+            .get(lang)
+            .map(|data| flatten_locale(data, &options.separator))
+            .unwrap_or_default()
+    };
+    let source = flatten(&options.source_language);
+    let target = flatten(target_language);
+    let keys: BTreeSet<&String> = source.keys().chain(target.keys()).collect();
+
+    let attr = |s: &str| escape(s).into_owned();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    match options.version {
+        XliffVersion::V1_2 => {
+            out.push_str("<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+            out.push_str(&format!(
+                "  <file original=\"localizekit\" datatype=\"plaintext\" source-language=\"{}\" target-language=\"{}\">\n",
+                attr(&options.source_language),
+                attr(target_language)
+            ));
+            out.push_str("    <body>\n");
+            for key in keys {
+                let source_text = source.get(key).map(value_to_cell).unwrap_or_default();
+                let target_text = target.get(key).map(value_to_cell).unwrap_or_default();
+
+                let metadata = result.metadata.get(key.as_str());
+                let max_width = metadata
+                    .and_then(|m| m.max_length)
+                    .map(|max| format!(" maxwidth=\"{}\" size-unit=\"char\"", max))
+                    .unwrap_or_default();
+                out.push_str(&format!("      <trans-unit id=\"{}\"{}>\n", attr(key), max_width));
+                out.push_str(&format!(
+                    "        <source>{}</source>\n",
+                    inline_content(&source_text, options.version)
+                ));
+                if target_text.is_empty() {
+                    out.push_str("        <target state=\"needs-translation\"></target>\n");
+                } else {
+                    out.push_str(&format!(
+                        "        <target state=\"translated\">{}</target>\n",
+                        inline_content(&target_text, options.version)
+                    ));
+                }
+                for (kind, note) in unit_notes(metadata, options.notes.get(key.as_str()), options.version) {
+                    out.push_str(&format!("        {}\n", note_element(kind, &note, options.version)));
+                }
+                out.push_str("      </trans-unit>\n");
+            }
+            out.push_str("    </body>\n  </file>\n</xliff>\n");
+        }
+        XliffVersion::V2_0 => {
+            out.push_str(&format!(
+                "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{}\" trgLang=\"{}\">\n",
+                attr(&options.source_language),
+                attr(target_language)
+            ));
+            out.push_str("  <file id=\"localizekit\">\n");
+            for key in keys {
+                let source_text = source.get(key).map(value_to_cell).unwrap_or_default();
+                let target_text = target.get(key).map(value_to_cell).unwrap_or_default();
+
+                out.push_str(&format!("    <unit id=\"{}\">\n", attr(key)));
+                let notes = unit_notes(
+                    result.metadata.get(key.as_str()),
+                    options.notes.get(key.as_str()),
+                    options.version,
+                );
+                if !notes.is_empty() {
+                    out.push_str("      <notes>\n");
+                    for (kind, note) in notes {
+                        out.push_str(&format!("        {}\n", note_element(kind, &note, options.version)));
+                    }
+                    out.push_str("      </notes>\n");
+                }
+                let state = if target_text.is_empty() { "initial" } else { "translated" };
+                out.push_str(&format!("      <segment state=\"{}\">\n", state));
+                out.push_str(&format!(
+                    "        <source>{}</source>\n",
+                    inline_content(&source_text, options.version)
+                ));
+                if !target_text.is_empty() {
+                    out.push_str(&format!(
+                        "        <target>{}</target>\n",
+                        inline_content(&target_text, options.version)
+                    ));
+                }
+                out.push_str("      </segment>\n    </unit>\n");
+            }
+            out.push_str("  </file>\n</xliff>\n");
+        }
+    }
+
+    out
+}
+
+/// 원문 언어를 제외한 모든 언어의 XLIFF 문서 생성 (언어 코드 -> XLIFF)
+pub fn to_xliff_files(result: &ParseResult, options: &XliffExportOptions) -> BTreeMap<String, String> {
+    result
+        .languages
+        .iter()
+        .filter(|lang| **lang != options.source_language)
+        .map(|lang| (lang.clone(), to_xliff(result, lang, options)))
+        .collect()
+}
+
+// ============================================================================
+// 가져오기
+// ============================================================================
+
+/// 현재 텍스트를 모으고 있는 요소
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Capture {
+    None,
+    Source,
+    Target,
+    Note,
+}
+
+fn xml_error(reader: &Reader<&[u8]>, err: impl std::fmt::Display) -> ParseError {
+    ParseError::xliff_parse_error(&format!("{} (byte {})", err, reader.buffer_position()))
+}
+
+/// 번역 후보 요소 (1.2 `<alt-trans>`, 2.0 `<mtc:matches>`)
+fn is_candidate_element(local_name: &[u8]) -> bool {
+    matches!(local_name, b"alt-trans" | b"matches")
+}
+
+/// 병합해도 되는 번역 상태인지 (1.2: `translated`/`signed-off`/`final`, 2.0: `translated`/`reviewed`/`final`)
+/// `state`가 없으면 완료된 번역으로 봅니다.
+pub fn is_accepted_state(state: Option<&str>) -> bool {
+    state.is_none_or(|s| matches!(s, "translated" | "signed-off" | "reviewed" | "final"))
+}
+
+/// 2.0 `<segment>` / `<ignorable>` 하나의 (ignorable 여부, source, target)
+type Segment = (bool, String, Option<String>);
+
+/// unit의 segment들을 이어 붙여 source/target 결정
+/// target은 모든 `<segment>`에 target이 있을 때만 만듭니다 (`<ignorable>`은 target이 없으면 source 사용).
+fn join_segments(unit: &mut XliffUnit, segments: Vec<Segment>) {
+    unit.source = segments.iter().map(|(_, source, _)| source.as_str()).collect();
+    let translated = segments.iter().filter(|(ignorable, _, _)| !ignorable).all(|(_, _, t)| t.is_some())
+        && segments.iter().any(|(ignorable, _, _)| !ignorable);
+    unit.target = translated.then(|| {
+        segments
+            .iter()
+            .map(|(_, source, target)| target.as_deref().unwrap_or(source))
+            .collect()
+    });
+}
+
+/// XLIFF 1.2 / 2.0 문서 파싱
+pub fn parse_document(data: &[u8]) -> Result<XliffDocument> {
+    let mut reader = Reader::from_reader(data);
+    let mut doc = XliffDocument::default();
+    let mut unit: Option<XliffUnit> = None;
+    let mut capture = Capture::None;
+    let mut buffer = String::new();
+    let mut saw_root = false;
+    // 번역 후보(`<alt-trans>`, 2.0 `<mtc:matches>`) 안의 source/target은 읽지 않음
+    let mut candidate_depth = 0usize;
+    // 현재 unit의 2.0 segment 목록과 segment 안인지 여부
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut in_segment = false;
+
+    loop {
+        let event = reader.read_event().map_err(|e| xml_error(&reader, e))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let is_candidate = is_candidate_element(e.local_name().as_ref());
+                if candidate_depth > 0 || is_candidate {
+                    if is_candidate && !is_empty {
+                        candidate_depth += 1;
+                    }
+                    continue;
+                }
+                match e.local_name().as_ref() {
+                    b"xliff" => {
+                        saw_root = true;
+                        if xml_attribute(e, "version").as_deref().is_some_and(|v| v.starts_with('2')) {
+                            doc.version = XliffVersion::V2_0;
+                        }
+                        doc.source_language = xml_attribute(e, "srcLang").map(|l| normalize_lang_code(&l));
+                        doc.target_language = xml_attribute(e, "trgLang").map(|l| normalize_lang_code(&l));
+                    }
+                    b"file" => {
+                        if let Some(l) = xml_attribute(e, "source-language") {
+                            doc.source_language = Some(normalize_lang_code(&l));
+                        }
+                        if let Some(l) = xml_attribute(e, "target-language") {
+                            doc.target_language = Some(normalize_lang_code(&l));
+                        }
+                    }
+                    b"trans-unit" | b"unit" => {
+                        let id = xml_attribute(e, "id")
+                            .or_else(|| xml_attribute(e, "resname"))
+                            .ok_or_else(|| xml_error(&reader, "translation unit without 'id'"))?;
+                        unit = Some(XliffUnit {
+                            id,
+                            ..Default::default()
+                        });
+                    }
+                    b"segment" | b"ignorable" if unit.is_some() => {
+                        // 여러 segment 중 하나라도 미완료 상태면 그 상태를 유지
+                        if let (Some(u), Some(state)) = (unit.as_mut(), xml_attribute(e, "state")) {
+                            if is_accepted_state(u.state.as_deref()) {
+                                u.state = Some(state);
+                            }
+                        }
+                        if !is_empty {
+                            segments.push((e.local_name().as_ref() == b"ignorable", String::new(), None));
+                            in_segment = true;
+                        }
+                    }
+                    b"source" if unit.is_some() => {
+                        capture = Capture::Source;
+                        buffer.clear();
+                    }
+                    b"target" if unit.is_some() => {
+                        if let Some(u) = unit.as_mut() {
+                            if let Some(state) = xml_attribute(e, "state") {
+                                u.state = Some(state);
+                            }
+                            if is_empty {
+                                match segments.last_mut() {
+                                    Some(segment) if in_segment => segment.2 = Some(String::new()),
+                                    _ => u.target = Some(String::new()),
+                                }
+                            }
+                        }
+                        capture = if is_empty { Capture::None } else { Capture::Target };
+                        buffer.clear();
+                    }
+                    b"note" if unit.is_some() && !is_empty => {
+                        capture = Capture::Note;
+                        buffer.clear();
+                    }
+                    b"ph" if is_empty && capture != Capture::None => {
+                        // 2.0: <ph equiv="{{name}}"/>, 1.2 빈 요소: equiv-text
+                        let code = xml_attribute(e, "equiv")
+                            .or_else(|| xml_attribute(e, "disp"))
+                            .or_else(|| xml_attribute(e, "equiv-text"))
+                            .unwrap_or_default();
+                        buffer.push_str(&code);
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(ref e) if candidate_depth > 0 && is_candidate_element(e.local_name().as_ref()) => {
+                candidate_depth -= 1;
+            }
+            Event::End(_) if candidate_depth > 0 => {}
+            Event::End(ref e) => match e.local_name().as_ref() {
+                b"source" if capture == Capture::Source => {
+                    match (segments.last_mut(), unit.as_mut()) {
+                        (Some(segment), _) if in_segment => segment.1 = std::mem::take(&mut buffer),
+                        (_, Some(u)) => u.source = std::mem::take(&mut buffer),
+                        _ => {}
+                    }
+                    capture = Capture::None;
+                }
+                b"target" if capture == Capture::Target => {
+                    match (segments.last_mut(), unit.as_mut()) {
+                        (Some(segment), _) if in_segment => segment.2 = Some(std::mem::take(&mut buffer)),
+                        (_, Some(u)) => u.target = Some(std::mem::take(&mut buffer)),
+                        _ => {}
+                    }
+                    capture = Capture::None;
+                }
+                b"segment" | b"ignorable" => in_segment = false,
+                b"note" if capture == Capture::Note => {
+                    if let Some(u) = unit.as_mut() {
+                        u.notes.push(buffer.trim().to_string());
+                    }
+                    capture = Capture::None;
+                }
+                b"trans-unit" | b"unit" => {
+                    if let Some(mut u) = unit.take() {
+                        if !segments.is_empty() {
+                            join_segments(&mut u, std::mem::take(&mut segments));
+                        }
+                        doc.units.push(u);
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(ref t) if capture != Capture::None => {
+                buffer.push_str(&t.decode().map_err(|e| xml_error(&reader, e))?);
+            }
+            Event::CData(ref t) if capture != Capture::None => {
+                buffer.push_str(&t.decode().map_err(|e| xml_error(&reader, e))?);
+            }
+            Event::GeneralRef(ref r) if capture != Capture::None => {
+                let name = r.decode().map_err(|e| xml_error(&reader, e))?;
+                let resolved = resolve_xml_entity(&name)
+                    .ok_or_else(|| xml_error(&reader, format!("unknown entity '&{};'", name)))?;
+                buffer.push_str(&resolved);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !saw_root {
+        return Err(ParseError::xliff_parse_error("missing <xliff> root element"));
+    }
+
+    Ok(doc)
+}
+
+/// 번역된 XLIFF를 ParseResult에 병합
+///
+/// 문서의 대상 언어 데이터에 비어 있지 않은 `target`을 덮어씁니다.
+/// `needs-translation`, `needs-review-*`, `new`, `initial` 등 완료되지 않은 상태의 target은 건너뜁니다
+/// (`is_accepted_state` 참고).
+/// 대상 언어가 `languages`에 없으면 추가합니다.
+/// 파싱된 문서를 함께 반환하므로 note 등을 그대로 활용할 수 있습니다.
+pub fn merge(result: &mut ParseResult, data: &[u8], options: &ParseOptions) -> Result<XliffDocument> {
+    let doc = parse_document(data)?;
+    let target_language = doc
+        .target_language
+        .clone()
+        .ok_or_else(|| ParseError::xliff_parse_error("document has no target language"))?;
+
+    let mut flat: BTreeMap<String, String> = result
+        .data
+        .get(&target_language)
+        .map(|data| flatten_locale(data, &options.separator))
+        .unwrap_or_default()
+        .iter()
+        .map(|(k, v)| (k.clone(), value_to_cell(v)))
+        .collect();
+
+    for u in doc.units.iter().filter(|u| is_accepted_state(u.state.as_deref())) {
+        if let Some(target) = u.target.as_ref().filter(|t| !t.is_empty()) {
+            flat.insert(u.id.clone(), target.clone());
+        }
+    }
+
+    result
+        .data
+        .insert(target_language.clone(), build_lang_map(flat.into_iter().collect(), options)?);
+    if !result.languages.contains(&target_language) {
+        result.languages.push(target_language);
+    }
+
+    Ok(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result() -> ParseResult {
+        let csv_str = "key,en,ko\ncommon.hello,Hello {{name}}!,안녕 {{name}}!\ncommon.bye,Bye & see you,\nitems,%d <items>,";
+        crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_export_v12() {
+        let mut options = XliffExportOptions::default();
+        options
+            .notes
+            .insert("common.bye".to_string(), vec!["Shown on logout".to_string()]);
+
+        let files = to_xliff_files(&sample_result(), &options);
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["ko"]);
+
+        let xml = files.get("ko").unwrap();
+        assert!(xml.contains("source-language=\"en\" target-language=\"ko\""));
+        assert!(xml.contains(
+            "<source>Hello <ph id=\"1\" equiv-text=\"{{name}}\">{{name}}</ph>!</source>"
+        ));
+        assert!(xml.contains("<target state=\"translated\">안녕 <ph id=\"1\""));
+        assert!(xml.contains("<source>Bye &amp; see you</source>"));
+        assert!(xml.contains("<target state=\"needs-translation\"></target>"));
+        assert!(xml.contains("<note>Shown on logout</note>"));
+        assert!(xml.contains("&lt;items&gt;"));
+    }
+
+    #[test]
+    fn test_export_v20() {
+        let options = XliffExportOptions {
+            version: XliffVersion::V2_0,
+            ..Default::default()
+        };
+        let xml = to_xliff(&sample_result(), "ko", &options);
+        assert!(xml.contains("srcLang=\"en\" trgLang=\"ko\""));
+        assert!(xml.contains("<ph id=\"ph1\" disp=\"{{name}}\" equiv=\"{{name}}\"/>"));
+        assert!(xml.contains("<segment state=\"initial\">"));
+    }
+
+    #[test]
+    fn test_export_metadata_notes() {
+        let csv_str = "key,en,ko,#description,#context,#maxLength\ngreeting,Hello,안녕,Home greeting,Header,20";
+        let result = crate::parser::csv::parse(csv_str.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let mut options = XliffExportOptions::default();
+        // 이전 문서에서 읽은 note가 메타데이터와 같으면 한 번만 출력
+        options.notes.insert(
+            "greeting".to_string(),
+            vec!["Home greeting".to_string(), "Reviewed".to_string()],
+        );
+        let xml = to_xliff(&result, "ko", &options);
+        assert!(xml.contains("<trans-unit id=\"greeting\" maxwidth=\"20\" size-unit=\"char\">"));
+        assert!(xml.contains(
+            "<note from=\"description\">Home greeting</note>\n        <note from=\"context\">Header</note>\n        <note>Reviewed</note>\n"
+        ));
+        assert_eq!(xml.matches("Home greeting").count(), 1);
+
+        options.version = XliffVersion::V2_0;
+        let xml = to_xliff(&result, "ko", &options);
+        assert!(xml.contains("<note category=\"maxLength\">20</note>"));
+        let doc = parse_document(xml.as_bytes()).unwrap();
+        assert_eq!(doc.units[0].notes, vec!["Home greeting", "Header", "20", "Reviewed"]);
+    }
+
+    #[test]
+    fn test_round_trip_both_versions() {
+        for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+            let options = XliffExportOptions {
+                version,
+                notes: BTreeMap::from([("items".to_string(), vec!["plural".to_string()])]),
+                ..Default::default()
+            };
+            let xml = to_xliff(&sample_result(), "ko", &options);
+            let doc = parse_document(xml.as_bytes()).unwrap();
+
+            assert_eq!(doc.version, version);
+            assert_eq!(doc.source_language.as_deref(), Some("en"));
+            assert_eq!(doc.target_language.as_deref(), Some("ko"));
+            let hello = doc.units.iter().find(|u| u.id == "common.hello").unwrap();
+            assert_eq!(hello.source, "Hello {{name}}!");
+            assert_eq!(hello.target.as_deref(), Some("안녕 {{name}}!"));
+            let bye = doc.units.iter().find(|u| u.id == "common.bye").unwrap();
+            assert_eq!(bye.source, "Bye & see you");
+            assert_eq!(doc.notes().get("items").unwrap(), &vec!["plural".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_merge_translated_xliff() {
+        let returned = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file source-language="en" target-language="ko" datatype="plaintext" original="x">
+    <body>
+      <trans-unit id="common.bye">
+        <source>Bye &amp; see you</source>
+        <target state="translated">잘 가 &amp; 또 봐</target>
+        <note>checked by agency</note>
+      </trans-unit>
+      <trans-unit id="items">
+        <source><ph id="1">%d</ph> &lt;items&gt;</source>
+        <target state="needs-review-translation"><ph id="1">%d</ph>개</target>
+      </trans-unit>
+      <trans-unit id="common.hello">
+        <source>Hello</source>
+        <target state="needs-translation"/>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+
+        let mut result = sample_result();
+        let doc = merge(&mut result, returned.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let ko = result.data.get("ko").unwrap();
+        assert_eq!(ko["common"]["bye"], "잘 가 & 또 봐");
+        assert_eq!(ko["common"]["hello"], "안녕 {{name}}!", "empty target keeps existing value");
+        assert!(ko.get("items").is_none(), "targets that still need review are not merged");
+        assert_eq!(doc.notes().get("common.bye").unwrap(), &vec!["checked by agency".to_string()]);
+    }
+
+    #[test]
+    fn test_alt_trans_is_ignored() {
+        let returned = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file source-language="en" target-language="ko" datatype="plaintext" original="x">
+    <body>
+      <trans-unit id="common.bye">
+        <source>Bye &amp; see you</source>
+        <target state="translated">잘 가</target>
+        <alt-trans match-quality="85%">
+          <source>Bye</source>
+          <target>안녕히 가세요</target>
+          <note>TM match</note>
+        </alt-trans>
+        <note>checked</note>
+      </trans-unit>
+      <trans-unit id="items">
+        <source>items</source>
+        <alt-trans><target>항목들</target></alt-trans>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+
+        let doc = parse_document(returned.as_bytes()).unwrap();
+        assert_eq!(doc.units[0].source, "Bye & see you");
+        assert_eq!(doc.units[0].target.as_deref(), Some("잘 가"));
+        assert_eq!(doc.units[0].notes, vec!["checked"]);
+        assert_eq!(doc.units[1].target, None);
+
+        let v2 = r#"<xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0"
+    xmlns:mtc="urn:oasis:names:tc:xliff:matches:2.0" srcLang="en" trgLang="ko">
+  <file id="f">
+    <unit id="greeting">
+      <mtc:matches>
+        <mtc:match><source>Hi</source><target>안녕</target></mtc:match>
+      </mtc:matches>
+      <segment><source>Hello</source><target>안녕하세요</target></segment>
+    </unit>
+  </file>
+</xliff>"#;
+        let doc = parse_document(v2.as_bytes()).unwrap();
+        assert_eq!(doc.units[0].source, "Hello");
+        assert_eq!(doc.units[0].target.as_deref(), Some("안녕하세요"));
+
+        let mut result = sample_result();
+        merge(&mut result, returned.as_bytes(), &ParseOptions::default()).unwrap();
+        assert_eq!(result.data["ko"]["common"]["bye"], "잘 가");
+    }
+
+    #[test]
+    fn test_unfinished_states_and_segments() {
+        let returned = r#"<xliff version="2.0" srcLang="en" trgLang="ko"><file id="f">
+  <unit id="common.bye">
+    <segment state="translated"><source>Bye.</source><target>잘 가.</target></segment>
+    <ignorable><source> </source></ignorable>
+    <segment state="final"><source>See you.</source><target>또 봐.</target></segment>
+  </unit>
+  <unit id="common.hello">
+    <segment state="translated"><source>Hello.</source><target>안녕.</target></segment>
+    <segment state="initial"><source>Welcome.</source><target>환영.</target></segment>
+  </unit>
+  <unit id="title">
+    <segment><source>A.</source><target>가.</target></segment>
+    <segment><source>B.</source></segment>
+  </unit>
+  <unit id="items"><segment state="initial"><source>items</source><target>항목</target></segment></unit>
+</file></xliff>"#;
+
+        let doc = parse_document(returned.as_bytes()).unwrap();
+        assert_eq!(doc.units[0].source, "Bye. See you.");
+        assert_eq!(doc.units[0].target.as_deref(), Some("잘 가. 또 봐."));
+        assert_eq!(doc.units[1].state.as_deref(), Some("initial"));
+        assert_eq!(doc.units[2].target, None, "partially translated unit has no target");
+
+        let mut result = sample_result();
+        merge(&mut result, returned.as_bytes(), &ParseOptions::default()).unwrap();
+        let ko = &result.data["ko"];
+        assert_eq!(ko["common"]["bye"], "잘 가. 또 봐.");
+        assert_eq!(ko["common"]["hello"], "안녕 {{name}}!");
+        assert!(ko.get("items").is_none() && ko.get("title").is_none());
+
+        for state in ["new", "needs-translation", "needs-review-translation", "needs-l10n"] {
+            assert!(!is_accepted_state(Some(state)), "{}", state);
+        }
+        assert!(is_accepted_state(None) && is_accepted_state(Some("signed-off")));
+    }
+
+    #[test]
+    fn test_merge_adds_new_language() {
+        let returned = r#"<xliff version="2.0" srcLang="en" trgLang="ja"><file id="f"><unit id="common.hello"><segment><source>Hello</source><target>こんにちは <ph id="1" equiv="{{name}}"/></target></segment></unit></file></xliff>"#;
+        let mut result = sample_result();
+        merge(&mut result, returned.as_bytes(), &ParseOptions::default()).unwrap();
+
+        assert_eq!(result.languages, vec!["en", "ko", "ja"]);
+        assert_eq!(result.data["ja"]["common"]["hello"], "こんにちは {{name}}");
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        assert_eq!(
+            parse_document(b"<root/>").unwrap_err().kind,
+            crate::error::ErrorKind::XliffParseError
+        );
+        assert!(parse_document(b"<xliff><file><trans-unit></trans-unit></file></xliff>").is_err());
+
+        let mut result = sample_result();
+        let err = merge(&mut result, b"<xliff version=\"1.2\"></xliff>", &ParseOptions::default())
+            .unwrap_err();
+        assert!(err.message.contains("target language"));
+    }
+}
@@ -0,0 +1,230 @@
+//! 의사 번역 (pseudo-localization)
+//!
+//! 실제 번역 전에 잘림, 하드코딩된 문자열, RTL 레이아웃 문제를 찾기 위한 가짜 언어를 만듭니다.
+//! - `en-XA`: 악센트 문자 + 길이 늘이기 + 괄호 표시 (예: `Hello {{name}}` -> `[Ĥéļļö {{name}} ~]`)
+//! - `ar-XB`: 텍스트를 RTL로 뒤집어 표시 (U+202E ... U+202C)
+//!
+//! 플레이스홀더, HTML 태그, `$t(...)` 참조는 그대로 둡니다.
+
+use crate::transform::{extract_html_tags, extract_nesting_references, extract_variables};
+use crate::types::ParseResult;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// RTL 텍스트 시작 (RIGHT-TO-LEFT OVERRIDE)
+const RTL_START: char = '\u{202E}';
+/// RTL 텍스트 끝 (POP DIRECTIONAL FORMATTING)
+const RTL_END: char = '\u{202C}';
+
+/// 의사 번역 옵션
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PseudoOptions {
+    /// 원문 언어 (없으면 첫 번째 언어)
+    pub source_language: Option<String>,
+    /// 만들 언어 코드 (없으면 `en-XA`, RTL이면 `ar-XB`)
+    pub language: Option<String>,
+    /// 늘릴 길이 비율 (0.3 = 텍스트 길이의 30%만큼 공백과 `~`를 뒤에 추가)
+    pub expansion: f64,
+    /// 문자열 앞뒤에 `[`, `]` 표시 (잘림 확인용)
+    pub brackets: bool,
+    /// RTL 변형: 악센트 대신 텍스트 방향을 뒤집음
+    pub rtl: bool,
+}
+
+impl Default for PseudoOptions {
+    fn default() -> Self {
+        Self {
+            source_language: None,
+            language: None,
+            expansion: 0.3,
+            brackets: true,
+            rtl: false,
+        }
+    }
+}
+
+impl PseudoOptions {
+    /// 만들 언어 코드
+    pub fn target_language(&self) -> String {
+        match (&self.language, self.rtl) {
+            (Some(language), _) => language.clone(),
+            (None, false) => "en-XA".to_string(),
+            (None, true) => "ar-XB".to_string(),
+        }
+    }
+}
+
+/// 원문 언어를 의사 번역하여 새 언어로 추가한 결과
+///
+/// 같은 코드의 언어가 이미 있으면 덮어씁니다. 원문 언어가 없으면 그대로 반환합니다.
+pub fn pseudo_localize(result: &ParseResult, options: &PseudoOptions) -> ParseResult {
+    let mut output = result.clone();
+    let source = options
+        .source_language
+        .as_ref()
+        .or_else(|| result.languages.first());
+    let Some(source_data) = source.and_then(|lang| result.data.get(lang)) else {
+        return output;
+    };
+
+    let language = options.target_language();
+    let data = source_data
+        .iter()
+        .map(|(key, value)| (key.clone(), pseudo_value(value, options)))
+        .collect();
+    output.data.insert(language.clone(), data);
+    if !output.languages.contains(&language) {
+        output.languages.push(language);
+    }
+    output
+}
+
+fn pseudo_value(value: &Value, options: &PseudoOptions) -> Value {
+    match value {
+        Value::String(s) => Value::String(pseudo_localize_text(s, options)),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), pseudo_value(v, options)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(|v| pseudo_value(v, options)).collect()),
+        other => other.clone(),
+    }
+}
+
+/// 문자열 하나를 의사 번역
+pub fn pseudo_localize_text(text: &str, options: &PseudoOptions) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    let mut text_len = 0;
+    for (segment, protected) in protected_segments(text) {
+        if protected {
+            out.push_str(segment);
+            continue;
+        }
+        text_len += segment.chars().count();
+        if options.rtl {
+            out.push(RTL_START);
+            out.push_str(segment);
+            out.push(RTL_END);
+        } else {
+            out.extend(segment.chars().map(accent));
+        }
+    }
+
+    let padding = (text_len as f64 * options.expansion.max(0.0)).ceil() as usize;
+    if padding > 0 {
+        out.push(' ');
+        out.extend(std::iter::repeat_n('~', padding.saturating_sub(1).max(1)));
+    }
+
+    if options.brackets {
+        format!("[{}]", out)
+    } else {
+        out
+    }
+}
+
+/// 문자열을 (조각, 보호 여부)로 분리 (이어 붙이면 원래 문자열)
+/// 보호 대상: `$t(...)`, 플레이스홀더, HTML 태그 (긴 것 우선)
+fn protected_segments(text: &str) -> Vec<(&str, bool)> {
+    let mut tokens: Vec<String> = extract_nesting_references(text);
+    tokens.extend(extract_variables(text).into_iter().map(|v| v.full_match));
+    tokens.extend(extract_html_tags(text));
+    tokens.retain(|t| t != "%%");
+    tokens.sort_by_key(|t| std::cmp::Reverse(t.len()));
+    tokens.dedup();
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut idx = 0;
+    while idx < text.len() {
+        let rest = &text[idx..];
+        if let Some(token) = tokens.iter().find(|t| rest.starts_with(t.as_str())) {
+            if start < idx {
+                segments.push((&text[start..idx], false));
+            }
+            segments.push((&text[idx..idx + token.len()], true));
+            idx += token.len();
+            start = idx;
+        } else {
+            idx += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if start < text.len() {
+        segments.push((&text[start..], false));
+    }
+    segments
+}
+
+/// ASCII 알파벳을 비슷하게 생긴 악센트 문자로 변환
+fn accent(c: char) -> char {
+    const UPPER: [char; 26] = [
+        'Å', 'Ɓ', 'Ç', 'Ð', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ', 'Š', 'Ţ', 'Û',
+        'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+    ];
+    const LOWER: [char; 26] = [
+        'á', 'ƀ', 'ç', 'ð', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ṁ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ', 'š', 'ţ', 'û',
+        'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+    ];
+    match c {
+        'A'..='Z' => UPPER[(c as u8 - b'A') as usize],
+        'a'..='z' => LOWER[(c as u8 - b'a') as usize],
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::types::ParseOptions;
+
+    #[test]
+    fn test_pseudo_localize_text() {
+        let options = PseudoOptions::default();
+        assert_eq!(pseudo_localize_text("Hello", &options), "[Ĥéļļö ~]");
+        assert_eq!(
+            pseudo_localize_text("Hi {{name}}, see <1>$t(common.link)</1> for 100%% of %d", &options),
+            "[Ĥî {{name}}, šéé <1>$t(common.link)</1> ƒöŕ 100%% öƒ %d ~~~~~~]"
+        );
+
+        let plain = PseudoOptions {
+            brackets: false,
+            expansion: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(pseudo_localize_text("Save {count}", &plain), "Šáṽé {count}");
+        assert_eq!(pseudo_localize_text("", &options), "");
+    }
+
+    #[test]
+    fn test_pseudo_localize_rtl() {
+        let options = PseudoOptions {
+            rtl: true,
+            brackets: false,
+            expansion: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(options.target_language(), "ar-XB");
+        assert_eq!(
+            pseudo_localize_text("Hi <b>{{name}}</b>", &options),
+            "\u{202E}Hi \u{202C}<b>{{name}}</b>"
+        );
+    }
+
+    #[test]
+    fn test_pseudo_localize_result() {
+        let csv = "key,en,ko\ncommon.save,Save,저장\ncommon.cancel,Cancel,취소";
+        let result = parser::csv::parse(csv.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let pseudo = pseudo_localize(&result, &PseudoOptions::default());
+        assert_eq!(pseudo.languages, vec!["en", "ko", "en-XA"]);
+        assert_eq!(pseudo.data["en-XA"]["common"]["save"], "[Šáṽé ~]");
+        assert_eq!(pseudo.data["en-XA"]["common"]["cancel"], "[Çáñçéļ ~]");
+    }
+}
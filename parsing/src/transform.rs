@@ -7,8 +7,14 @@
 //! - 복수형 키
 //! - Nesting 참조
 
+use crate::error::Result;
+use crate::types::{KeyOrder, LocaleData};
 use regex_lite::Regex;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 // ============================================================================
 // Regex 패턴들 (LazyLock으로 한 번만 컴파일)
@@ -28,7 +34,7 @@ static RE_PRINTF: LazyLock<Regex> =
 
 /// HTML 태그: <b>, </b>, <a href="...">, <br/>, <1>, </1>
 static RE_HTML_TAG: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"</?[a-zA-Z0-9]+(?:\s+[^>]*)?>").unwrap());
+    LazyLock::new(|| Regex::new(r"</?[a-zA-Z0-9]+(?:\s+[^>]*)?/?>").unwrap());
 
 /// react-i18next nesting: $t(key), $t(key, { "param": "value" })
 static RE_NESTING: LazyLock<Regex> =
@@ -127,7 +133,7 @@ pub enum VariableType {
 }
 
 /// 발견된 변수 정보
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Variable {
     pub var_type: VariableType,
     pub full_match: String,
@@ -172,6 +178,45 @@ pub fn extract_variables(input: &str) -> Vec<Variable> {
     variables
 }
 
+/// 변수 기준으로 나눈 문자열 조각
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringSegment {
+    Text(String),
+    Variable(Variable),
+}
+
+/// 문자열을 일반 텍스트와 변수 조각으로 분리 (원래 순서 유지)
+///
+/// 변환 결과를 이어 붙이면 원래 문자열과 같습니다.
+/// 포맷 변환 시 플레이스홀더만 다르게 처리할 때 사용합니다.
+pub fn split_variables(input: &str) -> Vec<StringSegment> {
+    let mut variables = extract_variables(input);
+    // 긴 패턴 우선 ({{name}}이 {name}보다 먼저 매칭되도록)
+    variables.sort_by_key(|v| std::cmp::Reverse(v.full_match.len()));
+    variables.dedup_by(|a, b| a.full_match == b.full_match);
+
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = input;
+    while let Some(c) = rest.chars().next() {
+        if let Some(var) = variables.iter().find(|v| rest.starts_with(v.full_match.as_str())) {
+            if !text.is_empty() {
+                segments.push(StringSegment::Text(std::mem::take(&mut text)));
+            }
+            rest = &rest[var.full_match.len()..];
+            segments.push(StringSegment::Variable(var.clone()));
+        } else {
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !text.is_empty() {
+        segments.push(StringSegment::Text(text));
+    }
+
+    segments
+}
+
 /// 문자열에 변수가 포함되어 있는지 확인
 pub fn has_variables(input: &str) -> bool {
     RE_DOUBLE_BRACE.is_match(input)
@@ -204,6 +249,63 @@ pub fn has_html_tags(input: &str) -> bool {
     RE_HTML_TAG.is_match(input)
 }
 
+/// 닫는 태그 없이 쓰는 HTML void 요소
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+
+/// 태그 종류
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagKind {
+    /// <b>, <a href="...">, <1>
+    Open,
+    /// </b>, </1>
+    Close,
+    /// <br/>, <br>, <img src="..." />
+    SelfClosing,
+}
+
+/// 발견된 HTML 태그 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlTag {
+    pub kind: TagKind,
+    /// 소문자로 정규화한 태그 이름 (react-i18next 번호 태그는 "1", "2" ...)
+    pub name: String,
+    pub full_match: String,
+}
+
+/// 문자열의 HTML 태그를 종류와 이름으로 분류하여 추출 (나온 순서 유지)
+pub fn parse_html_tags(input: &str) -> Vec<HtmlTag> {
+    RE_HTML_TAG
+        .find_iter(input)
+        .map(|m| {
+            let full_match = m.as_str();
+            let inner = full_match.trim_start_matches('<');
+            let is_close = inner.starts_with('/');
+            let name: String = inner
+                .trim_start_matches('/')
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase();
+
+            let kind = if is_close {
+                TagKind::Close
+            } else if full_match.ends_with("/>") || VOID_ELEMENTS.contains(&name.as_str()) {
+                TagKind::SelfClosing
+            } else {
+                TagKind::Open
+            };
+
+            HtmlTag {
+                kind,
+                name,
+                full_match: full_match.to_string(),
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // Nesting 참조 처리 ($t(key))
 // ============================================================================
@@ -228,36 +330,105 @@ pub fn has_nesting_references(input: &str) -> bool {
 /// CSV 텍스트의 첫 번째 컬럼(key)에 포함된 구분자를 원하는 구분자로 치환합니다.
 ///
 /// - 헤더(`key, ...`)는 그대로 둡니다.
-/// - 첫 번째 컬럼만 치환하고, 나머지 컬럼은 수정하지 않습니다.
+/// - 첫 번째 컬럼만 치환하고, 나머지 컬럼과 줄바꿈(`\n`, `\r\n`)은 원본 바이트 그대로 둡니다.
+/// - 따옴표로 감싼 key는 따옴표를 유지합니다 (`"a.b,c"` -> `"a/b,c"`).
 /// - 치환 대상 구분자: `.`, `/`, `-`
-pub fn rewrite_key_separator_in_csv(input: &str, target_sep: &str) -> String {
-    let mut output = Vec::new();
-
-    for (idx, line) in input.lines().enumerate() {
+/// - CSV를 끝까지 읽지 못하면 일부만 바꾼 결과 대신 에러를 반환합니다.
+pub fn rewrite_key_separator_in_csv(input: &str, target_sep: &str) -> Result<String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(input.as_bytes());
+
+    let mut output = String::with_capacity(input.len());
+    let mut copied = 0;
+
+    for (idx, record) in reader.byte_records().enumerate() {
+        let record = record?;
         // 헤더는 그대로 둔다
         if idx == 0 {
-            output.push(line.to_string());
             continue;
         }
 
-        // 첫 번째 콤마까지가 key
-        if let Some(comma_idx) = line.find(',') {
-            let (key_part, rest) = line.split_at(comma_idx);
-            let mut new_key = String::with_capacity(key_part.len());
-            for ch in key_part.chars() {
-                match ch {
-                    '.' | '/' | '-' => new_key.push_str(target_sep),
-                    _ => new_key.push(ch),
-                }
+        let position = record.position().map_or(copied, |pos| pos.byte() as usize);
+        let Some((start, end, quoted)) = raw_first_field(input, position) else {
+            continue;
+        };
+        let Some(key) = record.get(0).and_then(|k| std::str::from_utf8(k).ok()) else {
+            continue;
+        };
+
+        let mut new_key = String::with_capacity(key.len());
+        for ch in key.chars() {
+            match ch {
+                '.' | '/' | '-' => new_key.push_str(target_sep),
+                _ => new_key.push(ch),
             }
-            output.push(format!("{new_key}{rest}"));
-        } else {
-            // 콤마가 없으면 그대로
-            output.push(line.to_string());
         }
+
+        if new_key == key {
+            continue;
+        }
+
+        output.push_str(&input[copied..start]);
+        output.push_str(&write_csv_field(&new_key, quoted));
+        copied = end;
+    }
+
+    output.push_str(&input[copied..]);
+    Ok(output)
+}
+
+/// `position`에서 시작하는 레코드의 첫 번째 필드 원본 범위 (시작, 끝, 따옴표 여부)
+/// (`\r\n`이면 reader의 위치가 `\n`을 가리키므로 앞의 줄바꿈은 건너뜀.
+/// 닫는 따옴표 뒤에 다른 문자가 붙은 비정상 필드는 `None`)
+fn raw_first_field(input: &str, position: usize) -> Option<(usize, usize, bool)> {
+    let rest = input.as_bytes().get(position..)?;
+    let start = position + rest.iter().take_while(|b| matches!(b, b'\r' | b'\n')).count();
+    let bytes = &input.as_bytes()[start..];
+    let is_field_end = |i: usize| matches!(bytes.get(i), None | Some(b',' | b'\r' | b'\n'));
+
+    if bytes.first() != Some(&b'"') {
+        let len = bytes
+            .iter()
+            .position(|b| matches!(b, b',' | b'\r' | b'\n'))
+            .unwrap_or(bytes.len());
+        return Some((start, start + len, false));
+    }
+
+    let mut i = 1;
+    while i < bytes.len() {
+        if bytes[i] == b'"' {
+            if bytes.get(i + 1) == Some(&b'"') {
+                i += 2;
+                continue;
+            }
+            return is_field_end(i + 1).then_some((start, start + i + 1, true));
+        }
+        i += 1;
     }
+    None
+}
 
-    output.join("\n")
+/// 필드 하나를 CSV로 쓰기 (`quoted`이면 항상 따옴표, 아니면 필요할 때만)
+fn write_csv_field(value: &str, quoted: bool) -> String {
+    let style = if quoted {
+        csv::QuoteStyle::Always
+    } else {
+        csv::QuoteStyle::Necessary
+    };
+    let mut writer = csv::WriterBuilder::new()
+        .quote_style(style)
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    // 필드는 레코드가 끝나야 닫는 따옴표까지 쓰이므로 한 줄로 쓰고 줄바꿈을 제거
+    let bytes = writer
+        .write_record([value])
+        .ok()
+        .and_then(|_| writer.into_inner().ok())
+        .unwrap_or_default();
+    let line = String::from_utf8(bytes).unwrap_or_else(|_| value.to_string());
+    line.strip_suffix('\n').unwrap_or(&line).to_string()
 }
 
 // ============================================================================
@@ -288,8 +459,8 @@ pub fn is_plural_key(key: &str) -> bool {
 /// 예: "items_one" -> "items", "items_other" -> "items"
 pub fn get_plural_base_key(key: &str) -> Option<String> {
     for suffix in PLURAL_SUFFIXES {
-        if key.ends_with(suffix) {
-            return Some(key[..key.len() - suffix.len()].to_string());
+        if let Some(base) = key.strip_suffix(suffix) {
+            return Some(base.to_string());
         }
     }
     None
@@ -322,55 +493,208 @@ pub fn is_context_key(key: &str) -> bool {
 /// context 키에서 기본 키 추출
 pub fn get_context_base_key(key: &str) -> Option<String> {
     for suffix in CONTEXT_SUFFIXES {
-        if key.ends_with(suffix) {
-            return Some(key[..key.len() - suffix.len()].to_string());
+        if let Some(base) = key.strip_suffix(suffix) {
+            return Some(base.to_string());
         }
     }
     None
 }
 
 // ============================================================================
-// LocaleData 정렬 (알파벳 순)
+// 값 타입 복원 (ParseOptions::typed_values)
 // ============================================================================
 
-use crate::types::LocaleData;
-use std::collections::BTreeMap;
+/// 셀 문자열을 JSON 값으로 해석 (일반 문자열이면 `None`)
+///
+/// - `true`, `false`, `null`
+/// - 숫자: 다시 문자열로 만들었을 때 같은 경우만 (`1`, `-2.5`는 숫자, `007`, `1e5`, `+1`은 문자열)
+/// - 배열: `[...]` 형태의 JSON 배열 (예: `["a", "b"]`)
+/// - 앞에 `'`를 붙이면 문자열로 유지 (`'true` -> `"true"`)
+pub fn parse_typed_value(text: &str) -> Option<serde_json::Value> {
+    use serde_json::Value;
+
+    match text {
+        "true" => return Some(Value::Bool(true)),
+        "false" => return Some(Value::Bool(false)),
+        "null" => return Some(Value::Null),
+        _ => {}
+    }
+
+    if let Some(rest) = text.strip_prefix('\'') {
+        return parse_typed_value(rest).map(|_| Value::String(rest.to_string()));
+    }
+
+    if text.starts_with('[') && text.ends_with(']') {
+        return serde_json::from_str::<Value>(text).ok().filter(Value::is_array);
+    }
+
+    if text.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+        if let Ok(Value::Number(number)) = serde_json::from_str::<Value>(text) {
+            if number.to_string() == text {
+                return Some(Value::Number(number));
+            }
+        }
+    }
+
+    None
+}
+
+/// 키가 모두 `0`, `1`, ... (빈 번호 없음)인 object를 배열로 변환 (재귀)
+/// 예: `{ "items": { "0": "a", "1": "b" } }` -> `{ "items": ["a", "b"] }`
+pub(crate) fn index_objects_to_arrays(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
 
-/// 값이 Object인 경우 키를 알파벳 순으로 정렬하여 새로운 Value로 반환
-pub(crate) fn sort_value(value: serde_json::Value) -> serde_json::Value {
     match value {
-        serde_json::Value::Object(map) => {
-            let mut keys: Vec<_> = map.keys().cloned().collect();
-            keys.sort();
+        Value::Object(map) => {
+            let is_index_list = !map.is_empty()
+                && (0..map.len()).all(|idx| map.contains_key(&idx.to_string()));
+            let mut map: serde_json::Map<String, Value> = map
+                .into_iter()
+                .map(|(k, v)| (k, index_objects_to_arrays(v)))
+                .collect();
+
+            if is_index_list {
+                Value::Array((0..map.len()).filter_map(|idx| map.remove(&idx.to_string())).collect())
+            } else {
+                Value::Object(map)
+            }
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(index_objects_to_arrays).collect()),
+        other => other,
+    }
+}
 
-            let mut sorted = serde_json::Map::new();
-            for k in keys {
-                if let Some(v) = map.get(&k) {
-                    sorted.insert(k, sort_value(v.clone()));
-                }
+// ============================================================================
+// LocaleData 정렬 (ParseOptions::key_order)
+// ============================================================================
+
+/// Object 키를 `order`에 따라 재귀적으로 정렬 (`Source`는 기존 순서 유지)
+pub fn order_value(value: serde_json::Value, order: KeyOrder) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, serde_json::Value)> = map
+                .into_iter()
+                .map(|(k, v)| (k, order_value(v, order)))
+                .collect();
+            match order {
+                KeyOrder::Alphabetical => entries.sort_by(|(a, _), (b, _)| a.cmp(b)),
+                KeyOrder::Natural => entries.sort_by(|(a, _), (b, _)| natural_cmp(a, b)),
+                KeyOrder::Source => {}
             }
-            serde_json::Value::Object(sorted)
+            serde_json::Value::Object(entries.into_iter().collect())
         }
         serde_json::Value::Array(arr) => {
-            serde_json::Value::Array(arr.into_iter().map(sort_value).collect())
+            serde_json::Value::Array(arr.into_iter().map(|v| order_value(v, order)).collect())
         }
         other => other,
     }
 }
 
-/// LocaleData의 각 언어별 데이터를 알파벳 순으로 정렬
-pub fn sort_locale_data(locale_data: LocaleData) -> LocaleData {
-    let mut sorted_locale: LocaleData = LocaleData::new();
+/// 키 목록을 `order`에 따라 정렬 (`Source`는 그대로)
+pub(crate) fn order_keys(keys: &mut [String], order: KeyOrder) {
+    match order {
+        KeyOrder::Alphabetical => keys.sort(),
+        KeyOrder::Natural => keys.sort_by(|a, b| natural_cmp(a, b)),
+        KeyOrder::Source => {}
+    }
+}
 
-    for (lang, data) in locale_data {
-        let mut sorted_lang: BTreeMap<String, serde_json::Value> = BTreeMap::new();
-        for (k, v) in data {
-            sorted_lang.insert(k, sort_value(v));
+/// 자연 정렬 비교: 숫자 부분은 크기로, 나머지는 문자 단위로 비교
+/// 예: `item2` < `item10`, `v1.9` < `v1.10`
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a_rest, mut b_rest) = (a, b);
+    while let (Some(ac), Some(bc)) = (a_rest.chars().next(), b_rest.chars().next()) {
+        if ac.is_ascii_digit() && bc.is_ascii_digit() {
+            let a_len = a_rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(a_rest.len());
+            let b_len = b_rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(b_rest.len());
+            let a_num = a_rest[..a_len].trim_start_matches('0');
+            let b_num = b_rest[..b_len].trim_start_matches('0');
+            let ordering = a_num.len().cmp(&b_num.len()).then_with(|| a_num.cmp(b_num));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            a_rest = &a_rest[a_len..];
+            b_rest = &b_rest[b_len..];
+        } else {
+            if ac != bc {
+                return ac.cmp(&bc);
+            }
+            a_rest = &a_rest[ac.len_utf8()..];
+            b_rest = &b_rest[bc.len_utf8()..];
         }
-        sorted_locale.insert(lang, sorted_lang);
     }
+    a_rest.len().cmp(&b_rest.len()).then_with(|| a.cmp(b))
+}
+
+/// 두 키 목록을 합치되 각 목록의 순서를 유지
+/// `secondary`에만 있는 키는 `secondary`에서 바로 앞에 있던 키 뒤에 끼워 넣습니다.
+/// (정렬된 두 목록을 합치면 정렬된 결과, 행 순서 목록이면 행 순서 결과)
+pub(crate) fn merge_key_order<'a>(
+    primary: impl IntoIterator<Item = &'a String>,
+    secondary: impl IntoIterator<Item = &'a String>,
+) -> Vec<&'a String> {
+    let primary: Vec<&String> = primary.into_iter().collect();
+    let known: HashSet<&String> = primary.iter().copied().collect();
+
+    // 앞 키(없으면 맨 앞) -> 그 뒤에 끼워 넣을 키
+    let mut inserted: HashMap<Option<&String>, Vec<&String>> = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut previous = None;
+    for key in secondary {
+        if !known.contains(key) && seen.insert(key) {
+            inserted.entry(previous).or_default().push(key);
+        }
+        previous = Some(key);
+    }
+
+    // 키를 쓰고, 그 뒤에 끼워 넣을 키를 차례로 (끼워 넣은 키 뒤의 키까지)
+    let emit = |key: Option<&'a String>, out: &mut Vec<&'a String>| {
+        let mut stack = vec![key];
+        while let Some(key) = stack.pop() {
+            out.extend(key);
+            if let Some(next) = inserted.get(&key) {
+                stack.extend(next.iter().rev().map(|k| Some(*k)));
+            }
+        }
+    };
+
+    let mut merged = Vec::with_capacity(primary.len() + seen.len());
+    emit(None, &mut merged);
+    for key in primary {
+        emit(Some(key), &mut merged);
+    }
+    merged
+}
+
+/// LocaleData의 각 언어별 데이터를 알파벳 순으로 정렬
+pub fn sort_locale_data(locale_data: LocaleData) -> LocaleData {
+    order_locale_data(locale_data, KeyOrder::Alphabetical)
+}
 
-    sorted_locale
+/// LocaleData의 각 언어별 데이터를 `order`에 따라 정렬
+pub fn order_locale_data(locale_data: LocaleData, order: KeyOrder) -> LocaleData {
+    locale_data
+        .into_iter()
+        .map(|(lang, data)| match order_value(serde_json::Value::Object(data), order) {
+            serde_json::Value::Object(map) => (lang, map),
+            _ => unreachable!("order_value keeps objects"),
+        })
+        .collect()
+}
+
+// ============================================================================
+// 문자열 길이 (validate::check_max_length)
+// ============================================================================
+
+/// 사용자가 보는 글자 수 (grapheme cluster 단위)
+/// 예: "é"(e + 결합 악센트), "👍🏽", "🇰🇷"는 모두 1글자
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// 화면 표시 폭 (글자별 East Asian Width 기준: 한글, 한자, 가나, 이모지 등 전각은 2칸)
+pub fn display_width(text: &str) -> usize {
+    text.graphemes(true).map(|g| g.width()).sum()
 }
 
 // ============================================================================
@@ -461,6 +785,23 @@ mod tests {
         assert_eq!(printf_vars[1].full_match, "%d");
     }
 
+    #[test]
+    fn test_split_variables() {
+        let segments = split_variables("Hi {{name}}, you have %d {x}");
+        let rendered: Vec<String> = segments
+            .iter()
+            .map(|s| match s {
+                StringSegment::Text(t) => format!("T({})", t),
+                StringSegment::Variable(v) => format!("V({})", v.full_match),
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            vec!["T(Hi )", "V({{name}})", "T(, you have )", "V(%d)", "T( )", "V({x})"]
+        );
+        assert!(split_variables("").is_empty());
+    }
+
     #[test]
     fn test_has_variables() {
         assert!(has_variables("Hello, {{name}}!"));
@@ -475,6 +816,25 @@ mod tests {
         assert_eq!(tags.len(), 4);
         assert!(tags.contains(&"<b>".to_string()));
         assert!(tags.contains(&"</b>".to_string()));
+
+        assert_eq!(extract_html_tags("a<br/>b<br />c"), vec!["<br/>", "<br />"]);
+    }
+
+    #[test]
+    fn test_parse_html_tags() {
+        let tags = parse_html_tags("<1>Hi</1> <B>x</B><br><img src='a.png'/>");
+        let kinds: Vec<(TagKind, &str)> = tags.iter().map(|t| (t.kind.clone(), t.name.as_str())).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (TagKind::Open, "1"),
+                (TagKind::Close, "1"),
+                (TagKind::Open, "b"),
+                (TagKind::Close, "b"),
+                (TagKind::SelfClosing, "br"),
+                (TagKind::SelfClosing, "img"),
+            ]
+        );
     }
 
     #[test]
@@ -532,12 +892,116 @@ mod tests {
         assert_eq!(analysis.plural_base_key, Some("items".to_string()));
     }
 
+    #[test]
+    fn test_parse_typed_value() {
+        use serde_json::json;
+
+        assert_eq!(parse_typed_value("true"), Some(json!(true)));
+        assert_eq!(parse_typed_value("null"), Some(json!(null)));
+        assert_eq!(parse_typed_value("-12"), Some(json!(-12)));
+        assert_eq!(parse_typed_value("2.5"), Some(json!(2.5)));
+        assert_eq!(parse_typed_value(r#"["a", 1]"#), Some(json!(["a", 1])));
+        assert_eq!(parse_typed_value("'42"), Some(json!("42")));
+
+        for text in ["True", "007", "1e5", "+1", "1.", "[1, 2", "[Draft]", "'hello", "Hello"] {
+            assert_eq!(parse_typed_value(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_index_objects_to_arrays() {
+        use serde_json::json;
+
+        let value = json!({
+            "list": { "1": "b", "0": "a", "2": { "0": "x" } },
+            "sparse": { "0": "a", "2": "c" },
+            "map": { "a": "1" },
+        });
+        assert_eq!(
+            index_objects_to_arrays(value),
+            json!({
+                "list": ["a", "b", ["x"]],
+                "sparse": { "0": "a", "2": "c" },
+                "map": { "a": "1" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut keys = vec!["item10", "item2", "Item1", "item02b", "v1.10", "v1.9", "item"];
+        keys.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(keys, vec!["Item1", "item", "item2", "item02b", "item10", "v1.9", "v1.10"]);
+    }
+
+    #[test]
+    fn test_merge_key_order() {
+        let keys = |list: &[&str]| list.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        let primary = keys(&["title", "save", "cancel"]);
+        let secondary = keys(&["intro", "title", "subtitle", "extra", "cancel", "footer"]);
+
+        let merged: Vec<&str> = merge_key_order(&primary, &secondary).into_iter().map(String::as_str).collect();
+        assert_eq!(merged, vec!["intro", "title", "subtitle", "extra", "save", "cancel", "footer"]);
+    }
+
+    #[test]
+    fn test_text_length() {
+        assert_eq!(grapheme_count("Cafe\u{301}"), 4);
+        assert_eq!(grapheme_count("👍🏽🇰🇷👨‍👩‍👧"), 3);
+        assert_eq!(grapheme_count("안녕하세요"), 5);
+        assert_eq!(display_width("안녕 OK"), 7);
+        assert_eq!(display_width("ｶﾀｶﾅ全角"), 8);
+        assert_eq!(display_width("𠀋"), 2);
+        assert_eq!(display_width("👍🏽 Cafe\u{301}"), 7);
+    }
+
     #[test]
     fn test_rewrite_key_separator_in_csv() {
         let input = "key,en,ko\ncommon.hello,Hello,안녕하세요\nauth/login,Login,로그인\nerrors-server,Error,에러";
-        let out = rewrite_key_separator_in_csv(input, "/");
+        let out = rewrite_key_separator_in_csv(input, "/").unwrap();
         let expected = "key,en,ko\ncommon/hello,Hello,안녕하세요\nauth/login,Login,로그인\nerrors/server,Error,에러";
         assert_eq!(out, expected);
     }
+
+    #[test]
+    fn test_rewrite_key_separator_in_csv_quoted() {
+        let input = "key,en\r\n\"a.b,c\",\"Hello,\r\nworld.x\"\r\n\"\"\"q\"\".d\",\"Say \"\"hi\"\"\"\r\n\r\ne-f,x.y\r\n";
+        let out = rewrite_key_separator_in_csv(input, "/").unwrap();
+        let expected = "key,en\r\n\"a/b,c\",\"Hello,\r\nworld.x\"\r\n\"\"\"q\"\"/d\",\"Say \"\"hi\"\"\"\r\n\r\ne/f,x.y\r\n";
+        assert_eq!(out, expected);
+
+        // 따옴표가 필요 없어도 원래 따옴표는 유지, BOM도 유지
+        let input = "\u{feff}key,en\n\"a.b\",A\n,empty";
+        assert_eq!(rewrite_key_separator_in_csv(input, "-").unwrap(), "\u{feff}key,en\n\"a-b\",A\n,empty");
+    }
+
+    /// `test_files`의 CSV: 구분자를 바꿔도 key 이외의 바이트는 그대로이고, 되돌리면 원본과 같아야 함
+    #[test]
+    fn test_rewrite_key_separator_round_trip() {
+        let files = [
+            include_str!("../test_files/small.csv"),
+            include_str!("../test_files/special_chars.csv"),
+            include_str!("../test_files/nested_deep.csv"),
+            include_str!("../test_files/many_languages.csv"),
+            include_str!("../test_files/medium.csv"),
+            include_str!("../test_files/large_special.csv"),
+        ];
+
+        for original in files {
+            for input in [original.to_string(), original.replace('\n', "\r\n")] {
+                let slashed = rewrite_key_separator_in_csv(&input, "/").unwrap();
+                assert_eq!(rewrite_key_separator_in_csv(&slashed, ".").unwrap(), input);
+
+                let mut before = csv::ReaderBuilder::new().has_headers(false).from_reader(input.as_bytes());
+                let mut after = csv::ReaderBuilder::new().has_headers(false).from_reader(slashed.as_bytes());
+                for (idx, (old, new)) in before.records().zip(after.records()).enumerate() {
+                    let (old, new) = (old.unwrap(), new.unwrap());
+                    let expected_key = if idx == 0 { old[0].to_string() } else { old[0].replace('.', "/") };
+                    assert_eq!(&new[0], expected_key);
+                    assert_eq!(old.iter().skip(1).collect::<Vec<_>>(), new.iter().skip(1).collect::<Vec<_>>());
+                }
+            }
+        }
+    }
 }
 
@@ -1,3 +1,4 @@
+use crate::error::ParseError;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 
@@ -10,6 +11,10 @@ pub struct HeaderInfo {
     pub languages: Vec<String>,
     /// 언어 코드 -> 컬럼 인덱스 매핑
     pub language_indices: HashMap<String, usize>,
+    /// 메타데이터 열과 컬럼 인덱스 (`#description` 등, 헤더 순서)
+    pub metadata_columns: Vec<(MetadataColumn, usize)>,
+    /// 파싱은 가능하지만 알려야 하는 문제 (알 수 없는 언어 코드 등)
+    pub warnings: Vec<ParseError>,
 }
 
 impl HeaderInfo {
@@ -18,6 +23,8 @@ impl HeaderInfo {
             has_valid_key_column: false,
             languages: Vec::new(),
             language_indices: HashMap::new(),
+            metadata_columns: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -28,8 +35,75 @@ impl Default for HeaderInfo {
     }
 }
 
+/// 언어 대신 키 정보를 담는 예약된 헤더 열
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataColumn {
+    /// 번역자를 위한 설명 (`#description`, `#comment`)
+    Description,
+    /// 문자열이 쓰이는 위치/상황 (`#context`)
+    Context,
+    /// 최대 글자 수 (`#maxLength`)
+    MaxLength,
+    /// 쉼표로 구분한 태그 (`#tags`)
+    Tags,
+}
+
+impl MetadataColumn {
+    /// 헤더 이름에서 변환 (대소문자, `#` 접두어, `-`/`_` 무시)
+    /// 예: "#description", "Description", "#max_length", "maxLength"
+    pub fn parse(name: &str) -> Option<Self> {
+        let name: String = name
+            .trim()
+            .trim_start_matches('#')
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "description" | "comment" => Some(Self::Description),
+            "context" => Some(Self::Context),
+            "maxlength" | "maxlen" => Some(Self::MaxLength),
+            "tags" => Some(Self::Tags),
+            _ => None,
+        }
+    }
+
+    /// 표준 헤더 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Description => "#description",
+            Self::Context => "#context",
+            Self::MaxLength => "#maxLength",
+            Self::Tags => "#tags",
+        }
+    }
+}
+
+/// 키 하나의 메타데이터 (시트의 메타데이터 열에서 읽음)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl KeyMetadata {
+    /// 담긴 정보가 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.context.is_none() && self.max_length.is_none() && self.tags.is_empty()
+    }
+}
+
 /// 파싱 옵션
+/// JSON에서 읽을 때 빠진 필드는 기본값을 사용합니다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ParseOptions {
     /// 키 구분자 (".", "/", "-")
     pub separator: String,
@@ -39,6 +113,23 @@ pub struct ParseOptions {
     pub output_format: OutputFormat,
     /// escape 시퀀스 처리 여부 (\n, \t 등을 실제 문자로 변환)
     pub process_escapes: bool,
+    /// 경고를 콘솔(네이티브: stderr, WASM: console.warn)에도 출력할지 여부
+    /// 경고는 항상 `ParseResult::warnings`에 담깁니다.
+    pub log_warnings: bool,
+    /// 같은 키가 여러 행에 있을 때의 처리 방법
+    pub duplicate_keys: DuplicateKeyPolicy,
+    /// `a`와 `a.b`가 함께 있을 때 `a`의 값을 담을 키 (예: "_", "$value")
+    /// 없으면 `NestedKeyConflict` 에러 (nested 모드에서만 의미 있음)
+    pub nested_leaf_key: Option<String>,
+    /// 기준 언어에는 있는데 비어 있는 번역을 `MissingTranslation` 에러로 처리
+    pub strict_missing: bool,
+    /// 누락 판단 기준 언어 (없으면 첫 번째 언어 컬럼)
+    pub reference_language: Option<String>,
+    /// 생성되는 JSON의 키 순서
+    pub key_order: KeyOrder,
+    /// 값 타입 복원: `true`/`false`/`null`, 숫자, `[...]` JSON 배열을 문자열 대신 해당 타입으로,
+    /// nested 모드에서 `items.0`, `items.1` 키를 배열로 변환 (`'true`처럼 `'`를 붙이면 문자열 유지)
+    pub typed_values: bool,
 }
 
 impl Default for ParseOptions {
@@ -48,6 +139,96 @@ impl Default for ParseOptions {
             nested: true,
             output_format: OutputFormat::Json,
             process_escapes: true,
+            log_warnings: false,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            nested_leaf_key: None,
+            strict_missing: false,
+            reference_language: None,
+            key_order: KeyOrder::default(),
+            typed_values: false,
+        }
+    }
+}
+
+/// 키 순서
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyOrder {
+    /// 알파벳(코드 포인트) 순
+    #[default]
+    Alphabetical,
+    /// 시트의 행 순서 (nested 키는 하위 키가 처음 나온 위치)
+    Source,
+    /// 숫자 부분을 크기로 비교하는 자연 정렬 (`item2` < `item10`)
+    Natural,
+}
+
+impl KeyOrder {
+    /// 문자열에서 변환 ("alphabetical"/"alpha", "source"/"original", "natural")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "alphabetical" | "alpha" => Some(Self::Alphabetical),
+            "source" | "original" => Some(Self::Source),
+            "natural" => Some(Self::Natural),
+            _ => None,
+        }
+    }
+}
+
+/// 중복 키 처리 정책
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateKeyPolicy {
+    /// `DuplicateKey` 에러로 파싱 실패
+    Error,
+    /// 처음 나온 행을 사용하고 이후 행은 무시
+    KeepFirst,
+    /// 마지막 행을 사용 (조용히 덮어쓰기)
+    KeepLast,
+    /// 마지막 행을 사용하고 `ParseResult::warnings`에 경고 추가
+    #[default]
+    Warn,
+}
+
+impl DuplicateKeyPolicy {
+    /// 문자열에서 변환 ("error", "keep_first"/"first", "keep_last"/"last", "warn")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "error" => Some(Self::Error),
+            "keep_first" | "first" => Some(Self::KeepFirst),
+            "keep_last" | "last" => Some(Self::KeepLast),
+            "warn" | "" => Some(Self::Warn),
+            _ => None,
+        }
+    }
+}
+
+/// Excel 시트 선택
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SheetSelection {
+    /// 0부터 시작하는 시트 번호
+    Index(usize),
+    /// 시트 이름
+    Name(String),
+    /// 모든 시트를 파싱하고 시트 이름을 최상위 네임스페이스로 사용
+    /// 예: `common` 시트의 `hello` -> `common.hello`
+    AllAsNamespaces,
+}
+
+impl Default for SheetSelection {
+    fn default() -> Self {
+        SheetSelection::Index(0)
+    }
+}
+
+impl SheetSelection {
+    /// 문자열에서 변환: "" -> 첫 번째 시트, "*" -> 모든 시트, 그 외 -> 시트 이름
+    /// (같은 이름의 시트가 없고 숫자이면 시트 번호로 취급)
+    pub fn from_str_lossy(value: &str) -> Self {
+        match value.trim() {
+            "" => SheetSelection::default(),
+            "*" => SheetSelection::AllAsNamespaces,
+            name => SheetSelection::Name(name.to_string()),
         }
     }
 }
@@ -63,8 +244,11 @@ pub enum OutputFormat {
 
 /// 파싱 결과: 언어별 키-값 맵
 /// 예: { "en": { "greeting": "Hello" }, "ko": { "greeting": "안녕" } }
-/// BTreeMap을 사용해 키 알파벳 순서를 보장
-pub type LocaleData = BTreeMap<String, BTreeMap<String, serde_json::Value>>;
+/// 언어는 코드 순으로 정렬되고, 각 언어의 키는 `ParseOptions::key_order` 순서를 유지
+///
+/// serde_json의 `preserve_order` 기능을 사용하므로 `serde_json::Map`은 삽입 순서를 따릅니다.
+/// 직접 만든 Map을 키 순으로 출력하려면 `transform::order_locale_data`로 정렬하세요.
+pub type LocaleData = BTreeMap<String, serde_json::Map<String, serde_json::Value>>;
 
 /// 파싱 결과
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,5 +259,53 @@ pub struct ParseResult {
     pub data: LocaleData,
     /// 파싱된 행 수
     pub row_count: usize,
+    /// 파싱은 성공했지만 알려야 하는 문제 (알 수 없는 언어 코드, 중복 언어 컬럼 등)
+    /// `location`의 `row`는 파일/시트의 실제 줄 번호 (헤더 = 1), `column`은 1부터 시작
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ParseError>,
+    /// 키별 메타데이터 (시트의 flat 키 -> `#description` 등 메타데이터 열의 값)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, KeyMetadata>,
+    /// 언어별 헤더 컬럼 번호 (key 컬럼 = 1). 시트가 아닌 입력(PO, Android 등)에서는 비어 있음
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub language_columns: BTreeMap<String, usize>,
 }
 
+
+/// 진단 모드 파싱 결과: 첫 에러에서 멈추지 않고 모든 문제를 수집
+#[derive(Debug, Clone)]
+pub struct ParseReport {
+    /// 에러가 없을 때만 파싱 결과 포함
+    pub result: Option<ParseResult>,
+    /// 모든 에러 (잘못된 행, 구분자가 다른 행 등)
+    pub errors: Vec<ParseError>,
+    /// 경고 (알 수 없는 언어 코드 등)
+    pub warnings: Vec<ParseError>,
+}
+
+impl ParseReport {
+    /// 단일 에러로 끝난 경우 (헤더 오류 등)
+    pub fn failed(error: ParseError) -> Self {
+        Self {
+            result: None,
+            errors: vec![error],
+            warnings: Vec::new(),
+        }
+    }
+
+    /// 에러가 없는지 여부
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// WASM용 JSON 문자열 (에러/경고는 `ParseError::to_json`과 같은 형식)
+    /// 예: `{ "result": {...} | null, "errors": [...], "warnings": [...] }`
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "result": self.result,
+            "errors": self.errors.iter().map(ParseError::to_json_value).collect::<Vec<_>>(),
+            "warnings": self.warnings.iter().map(ParseError::to_json_value).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+}
@@ -0,0 +1,690 @@
+//! 번역 품질 검증
+//!
+//! 파싱된 `ParseResult`를 원문 언어와 비교해 번역 문제를 찾습니다.
+//! 결과는 `ParseError` 목록으로 반환되며, `location`에 key와 언어 컬럼이 담깁니다.
+//! (`column`은 헤더 기준 1부터 시작, key 컬럼이 1)
+
+use crate::error::ParseError;
+use crate::export::flatten_locale;
+use crate::parser::{has_plural_sibling, plural_quantity};
+use crate::transform::{
+    display_width, extract_variables, get_plural_base_key, grapheme_count, parse_html_tags, HtmlTag, TagKind, Variable,
+    VariableType,
+};
+use crate::types::ParseResult;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// 원문 언어 결정: 지정값이 있으면 사용, 없으면 첫 번째 언어
+fn source_language<'a>(result: &'a ParseResult, source: Option<&'a str>) -> Option<&'a str> {
+    source.or_else(|| result.languages.first().map(String::as_str))
+}
+
+/// 언어별 flat 문자열 맵 (문자열이 아닌 값과 빈 문자열은 제외)
+fn flat_strings(result: &ParseResult, language: &str, separator: &str) -> BTreeMap<String, String> {
+    result
+        .data
+        .get(language)
+        .map(|data| flatten_locale(data, separator))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            Value::String(s) if !s.is_empty() => Some((key, s)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 언어의 헤더 컬럼 번호 (key 컬럼 = 1). 시트에서 파싱한 결과가 아니면 `None`
+fn language_column(result: &ParseResult, language: &str) -> Option<usize> {
+    result.language_columns.get(language).copied()
+}
+
+/// 비교 기준이 되는 플레이스홀더 (문자열에 나온 순서, 중복 제거)
+/// `%%`는 이스케이프된 `%` 문자이므로 제외합니다.
+fn placeholders(text: &str) -> Vec<Variable> {
+    let mut found: Vec<Variable> = Vec::new();
+    for var in extract_variables(text) {
+        if var.full_match == "%%" || found.iter().any(|v| v.full_match == var.full_match) {
+            continue;
+        }
+        found.push(var);
+    }
+    found
+}
+
+/// 원문과 번역의 플레이스홀더 비교
+///
+/// 모든 언어의 각 키를 원문 언어(`source_language`, 없으면 첫 번째 언어)와 비교해
+/// 빠진 것(`PlaceholderMissing`), 추가된 것(`PlaceholderExtra`),
+/// 같은 종류인데 이름이 다른 것(`PlaceholderRenamed`),
+/// 이름은 같은데 문법이 다른 것(`PlaceholderTypeMismatch`)을 찾습니다.
+///
+/// 복수형 키(`_one`, `_zero` 등)에서 `count`가 빠진 것은 자연스러운 번역이므로 보고하지 않습니다.
+/// 양쪽 모두 값이 있는 키만 비교합니다.
+pub fn check_placeholders(result: &ParseResult, source_language: Option<&str>, separator: &str) -> Vec<ParseError> {
+    let mut issues = Vec::new();
+    for_each_translation(result, source_language, separator, |key, language, column, source_text, text| {
+        compare_placeholders(key, language, column, source_text, text, &mut issues);
+    });
+    issues
+}
+
+/// 원문 언어가 아닌 각 언어의 (key, 언어, 컬럼, 원문, 번역)마다 `compare` 호출
+/// 양쪽 모두 값이 있는 키만 전달합니다.
+fn for_each_translation(
+    result: &ParseResult,
+    source_language: Option<&str>,
+    separator: &str,
+    mut compare: impl FnMut(&str, &str, Option<usize>, &str, &str),
+) {
+    let Some(source) = self::source_language(result, source_language) else {
+        return;
+    };
+    let source_values = flat_strings(result, source, separator);
+
+    for language in result.languages.iter().filter(|l| l.as_str() != source) {
+        let column = language_column(result, language);
+        for (key, text) in flat_strings(result, language, separator) {
+            if let Some(source_text) = source_values.get(&key) {
+                compare(&key, language, column, source_text, &text);
+            }
+        }
+    }
+}
+
+fn compare_placeholders(
+    key: &str,
+    language: &str,
+    column: Option<usize>,
+    source_text: &str,
+    text: &str,
+    issues: &mut Vec<ParseError>,
+) {
+    let expected = placeholders(source_text);
+    let found = placeholders(text);
+
+    let mut missing: Vec<&Variable> = expected
+        .iter()
+        .filter(|e| !found.iter().any(|f| f.full_match == e.full_match))
+        .collect();
+    let mut extra: Vec<&Variable> = found
+        .iter()
+        .filter(|f| !expected.iter().any(|e| e.full_match == f.full_match))
+        .collect();
+
+    // 같은 이름, 다른 문법: {{name}} -> {name}
+    missing.retain(|m| {
+        let Some(pos) = extra.iter().position(|e| e.name.is_some() && e.name == m.name) else {
+            return true;
+        };
+        let e = extra.remove(pos);
+        issues.push(ParseError::placeholder_type_mismatch(
+            key,
+            language,
+            column,
+            &m.full_match,
+            &e.full_match,
+        ));
+        false
+    });
+
+    // 같은 문법, 다른 이름: {{name}} -> {{nmae}} (나온 순서대로 짝지음)
+    missing.retain(|m| {
+        if m.var_type == VariableType::Printf {
+            return true;
+        }
+        let Some(pos) = extra.iter().position(|e| e.var_type == m.var_type) else {
+            return true;
+        };
+        let e = extra.remove(pos);
+        issues.push(ParseError::placeholder_renamed(key, language, column, &m.full_match, &e.full_match));
+        false
+    });
+
+    let is_plural = plural_quantity(key).is_some();
+    for m in missing {
+        if is_plural && m.name.as_deref() == Some("count") {
+            continue;
+        }
+        issues.push(ParseError::placeholder_missing(key, language, column, &m.full_match));
+    }
+    for e in extra {
+        issues.push(ParseError::placeholder_extra(key, language, column, &e.full_match));
+    }
+}
+
+/// HTML/마크업 태그 검사
+///
+/// 1. 모든 언어(원문 포함)의 각 문자열에서 태그 짝이 맞는지 확인합니다 (`TagUnbalanced`).
+///    react-i18next 번호 태그(`<1></1>`)도 일반 태그처럼 검사하고,
+///    `<br>`, `<img>` 같은 void 요소와 `<br/>`는 닫는 태그가 없어도 됩니다.
+/// 2. 번역의 태그 이름 목록을 원문과 비교해 빠진 태그(`TagMissing`)와
+///    추가된 태그(`TagExtra`)를 찾습니다. 속성(`href` 등)은 비교하지 않습니다.
+pub fn check_tags(result: &ParseResult, source_language: Option<&str>, separator: &str) -> Vec<ParseError> {
+    let mut issues = Vec::new();
+
+    for language in &result.languages {
+        let column = language_column(result, language);
+        for (key, text) in flat_strings(result, language, separator) {
+            for details in tag_balance_problems(&parse_html_tags(&text)) {
+                issues.push(ParseError::tag_unbalanced(&key, language, column, &details));
+            }
+        }
+    }
+
+    for_each_translation(result, source_language, separator, |key, language, column, source_text, text| {
+        compare_tags(key, language, column, source_text, text, &mut issues);
+    });
+
+    issues
+}
+
+/// 한 문자열 안의 태그 짝 검사 결과 (문제 설명 목록)
+fn tag_balance_problems(tags: &[HtmlTag]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut open: Vec<&HtmlTag> = Vec::new();
+
+    for tag in tags {
+        match tag.kind {
+            TagKind::Open => open.push(tag),
+            TagKind::SelfClosing => {}
+            TagKind::Close => match open.iter().rposition(|o| o.name == tag.name) {
+                Some(pos) => {
+                    // 안쪽에서 닫히지 않은 태그: <b><i></b>
+                    for unclosed in open.drain(pos..).skip(1) {
+                        problems.push(format!("tag {} is not closed before {}", unclosed.full_match, tag.full_match));
+                    }
+                }
+                None => problems.push(format!("closing tag {} has no opening tag", tag.full_match)),
+            },
+        }
+    }
+
+    for unclosed in open {
+        problems.push(format!("tag {} is not closed", unclosed.full_match));
+    }
+    problems
+}
+
+/// 원문과 번역의 태그 이름 비교 (여는 태그와 단독 태그만, 개수 포함)
+fn compare_tags(
+    key: &str,
+    language: &str,
+    column: Option<usize>,
+    source_text: &str,
+    text: &str,
+    issues: &mut Vec<ParseError>,
+) {
+    let starting = |text: &str| -> Vec<HtmlTag> {
+        parse_html_tags(text)
+            .into_iter()
+            .filter(|t| t.kind != TagKind::Close)
+            .collect()
+    };
+    let expected = starting(source_text);
+    let mut found = starting(text);
+
+    for tag in &expected {
+        match found.iter().position(|f| f.name == tag.name) {
+            Some(pos) => {
+                found.remove(pos);
+            }
+            None => issues.push(ParseError::tag_missing(key, language, column, &tag.full_match)),
+        }
+    }
+    for tag in found {
+        issues.push(ParseError::tag_extra(key, language, column, &tag.full_match));
+    }
+}
+
+/// 언어별 번역 현황
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LanguageCoverage {
+    pub language: String,
+    /// 헤더 컬럼 번호 (key 컬럼 = 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// 기준 언어의 키 중 번역된 개수
+    pub translated: usize,
+    /// 누락된 키 개수 (`missing.len()`)
+    pub missing_count: usize,
+    /// 누락된 키 목록 (정렬됨)
+    pub missing: Vec<String>,
+    /// 완료율 (0.0 ~ 100.0, 기준 키가 없으면 100.0)
+    pub percent: f64,
+}
+
+/// 번역 현황 리포트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageReport {
+    pub reference_language: String,
+    /// 기준 언어의 키 개수
+    pub total_keys: usize,
+    /// `ParseResult::languages` 순서 (기준 언어 포함)
+    pub languages: Vec<LanguageCoverage>,
+}
+
+impl CoverageReport {
+    /// strict 모드용: 누락마다 `MissingTranslation` 에러 (`column`은 언어 컬럼)
+    pub fn missing_errors(&self) -> Vec<ParseError> {
+        self.languages
+            .iter()
+            .flat_map(|coverage| {
+                coverage
+                    .missing
+                    .iter()
+                    .map(|key| ParseError::missing_translation(key, &coverage.language, None, coverage.column))
+            })
+            .collect()
+    }
+}
+
+/// 언어별 번역 완료율과 누락 키 목록
+///
+/// 기준 언어(`reference_language`, 없으면 첫 번째 언어)에 값이 있는 키를 기준으로 계산합니다.
+/// 복수형 키는 같은 기본 키의 다른 형태가 있으면 번역된 것으로 봅니다 (언어마다 복수형 카테고리가 다름).
+pub fn coverage_report(result: &ParseResult, reference_language: Option<&str>, separator: &str) -> CoverageReport {
+    let reference = source_language(result, reference_language).unwrap_or_default();
+    let reference_keys = flat_strings(result, reference, separator);
+
+    let languages = result
+        .languages
+        .iter()
+        .map(|language| {
+            let values = flat_strings(result, language, separator);
+            let has = |k: &str| values.contains_key(k);
+            let missing: Vec<String> = reference_keys
+                .keys()
+                .filter(|key| !has(key) && !has_plural_sibling(key, has))
+                .cloned()
+                .collect();
+            let translated = reference_keys.len() - missing.len();
+            let percent = if reference_keys.is_empty() {
+                100.0
+            } else {
+                translated as f64 * 100.0 / reference_keys.len() as f64
+            };
+
+            LanguageCoverage {
+                language: language.clone(),
+                column: language_column(result, language),
+                translated,
+                missing_count: missing.len(),
+                missing,
+                percent,
+            }
+        })
+        .collect();
+
+    CoverageReport {
+        reference_language: reference.to_string(),
+        total_keys: reference_keys.len(),
+        languages,
+    }
+}
+
+/// 최대 길이 측정 단위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthUnit {
+    /// 글자 수 (grapheme cluster 단위, 결합 문자/이모지 시퀀스도 1글자)
+    #[default]
+    Graphemes,
+    /// 화면 표시 폭 (한글, 한자, 가나 등 전각 문자는 2칸)
+    DisplayWidth,
+}
+
+impl LengthUnit {
+    /// 문자열에서 변환 ("graphemes"/"chars", "display_width"/"width")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "graphemes" | "chars" | "" => Some(Self::Graphemes),
+            "display_width" | "width" => Some(Self::DisplayWidth),
+            _ => None,
+        }
+    }
+
+    /// 문자열 길이
+    pub fn measure(self, text: &str) -> usize {
+        match self {
+            Self::Graphemes => grapheme_count(text),
+            Self::DisplayWidth => display_width(text),
+        }
+    }
+}
+
+/// 최대 길이를 넘은 번역
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LengthOverflow {
+    pub key: String,
+    pub max_length: usize,
+    /// `LengthReport::unit` 기준 길이
+    pub length: usize,
+}
+
+/// 언어별 최대 길이 초과 목록
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LanguageOverflows {
+    pub language: String,
+    /// 헤더 컬럼 번호 (key 컬럼 = 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// 초과한 키 목록 (키 순)
+    pub overflows: Vec<LengthOverflow>,
+}
+
+/// 최대 길이 검사 리포트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LengthReport {
+    pub unit: LengthUnit,
+    /// 최대 길이가 정해진 키 개수
+    pub limited_keys: usize,
+    /// `ParseResult::languages` 순서 (초과가 없는 언어 포함)
+    pub languages: Vec<LanguageOverflows>,
+}
+
+impl LengthReport {
+    /// 초과한 번역이 없는지 여부
+    pub fn is_ok(&self) -> bool {
+        self.languages.iter().all(|lang| lang.overflows.is_empty())
+    }
+
+    /// 초과마다 `MaxLengthExceeded` 에러 (`column`은 언어 컬럼)
+    pub fn errors(&self) -> Vec<ParseError> {
+        self.languages
+            .iter()
+            .flat_map(|lang| {
+                lang.overflows
+                    .iter()
+                    .map(|o| ParseError::max_length_exceeded(&o.key, &lang.language, lang.column, o.max_length, o.length))
+            })
+            .collect()
+    }
+}
+
+/// 키별 최대 길이 검사
+///
+/// 최대 길이는 `limits`(외부 맵, flat 키 -> 길이)와 `result.metadata`의 `max_length`(시트의 `#maxLength` 열)에서
+/// 가져오고, 둘 다 있으면 `limits`를 사용합니다. 복수형 키(`items_one`)는 자신의 제한이 없으면 기본 키(`items`)의 제한을 따릅니다.
+/// 원문을 포함한 모든 언어의 값을 `unit` 기준으로 재며, 플레이스홀더도 글자 그대로 셉니다.
+pub fn check_max_length(
+    result: &ParseResult,
+    limits: &BTreeMap<String, usize>,
+    unit: LengthUnit,
+    separator: &str,
+) -> LengthReport {
+    let mut max_lengths: BTreeMap<&str, usize> = result
+        .metadata
+        .iter()
+        .filter_map(|(key, metadata)| Some((key.as_str(), metadata.max_length?)))
+        .collect();
+    max_lengths.extend(limits.iter().map(|(key, max)| (key.as_str(), *max)));
+
+    let limit_for = |key: &str| {
+        max_lengths
+            .get(key)
+            .or_else(|| get_plural_base_key(key).and_then(|base| max_lengths.get(base.as_str())))
+            .copied()
+    };
+
+    let languages = result
+        .languages
+        .iter()
+        .map(|language| {
+            let overflows = flat_strings(result, language, separator)
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    let max_length = limit_for(&key)?;
+                    let length = unit.measure(&value);
+                    (length > max_length).then_some(LengthOverflow { key, max_length, length })
+                })
+                .collect();
+            LanguageOverflows {
+                language: language.clone(),
+                column: language_column(result, language),
+                overflows,
+            }
+        })
+        .collect();
+
+    LengthReport {
+        unit,
+        limited_keys: max_lengths.len(),
+        languages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::parser;
+    use crate::types::ParseOptions;
+
+    fn parse(csv: &str) -> ParseResult {
+        parser::csv::parse(csv.as_bytes(), &ParseOptions::default()).unwrap()
+    }
+
+    fn kinds(issues: &[ParseError]) -> Vec<(ErrorKind, String, String)> {
+        issues
+            .iter()
+            .map(|i| {
+                let location = i.location.as_ref().unwrap();
+                (
+                    i.kind.clone(),
+                    location.key.clone().unwrap(),
+                    location.column_name.clone().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_placeholders() {
+        let result = parse(
+            "key,en,ko,ja\n\
+             greet,Hello {{name}},안녕 {{nmae}},こんにちは {name}\n\
+             count,%d files in %s,%d 파일,%d ファイル %s %1$s\n\
+             ok,Hi {{name}} {{place}},{{place}} {{name}} 안녕,\n\
+             pct,100%% done,100%% 완료,",
+        );
+
+        let issues = check_placeholders(&result, None, ".");
+        assert_eq!(
+            kinds(&issues),
+            vec![
+                (ErrorKind::PlaceholderMissing, "count".into(), "ko".into()),
+                (ErrorKind::PlaceholderRenamed, "greet".into(), "ko".into()),
+                (ErrorKind::PlaceholderExtra, "count".into(), "ja".into()),
+                (ErrorKind::PlaceholderTypeMismatch, "greet".into(), "ja".into()),
+            ]
+        );
+
+        let renamed = &issues[1];
+        assert!(renamed.message.contains("{{nmae}} was found instead of {{name}}"));
+        assert_eq!(renamed.location.as_ref().unwrap().column, Some(3));
+    }
+
+    #[test]
+    fn test_issue_column_uses_header_position() {
+        // description, #note, 중복된 en 컬럼이 있어도 실제 헤더 위치를 보고
+        let result = parse(
+            "key,description,en,#note,en,ko\n\
+             greet,Greeting,Hello {{name}},memo,Hi,안녕",
+        );
+        assert_eq!(result.language_columns.get("ko"), Some(&6));
+
+        let issues = check_placeholders(&result, None, ".");
+        let location = issues[0].location.as_ref().unwrap();
+        assert_eq!((location.column, location.column_name.as_deref()), (Some(6), Some("ko")));
+
+        let report = coverage_report(&parse("key,ko,#x,en\na,가,,"), Some("ko"), ".");
+        let location = report.missing_errors()[0].location.clone().unwrap();
+        assert_eq!((location.column, location.column_name.as_deref()), (Some(4), Some("en")));
+    }
+
+    #[test]
+    fn test_check_placeholders_source_and_plurals() {
+        let result = parse(
+            "key,en,ko\n\
+             item_one,One item,항목 {{count}}개\n\
+             item_other,{{count}} items,항목\n\
+             only_ko,,{{x}}",
+        );
+
+        // ko의 item_other에서 count가 빠졌지만 복수형이므로 허용
+        assert!(check_placeholders(&result, None, ".")
+            .iter()
+            .all(|i| i.kind == ErrorKind::PlaceholderExtra));
+
+        // ko 기준으로 보면 en의 item_one에서 count가 빠졌지만 복수형이므로 무시
+        let issues = check_placeholders(&result, Some("ko"), ".");
+        assert_eq!(kinds(&issues), vec![(ErrorKind::PlaceholderExtra, "item_other".into(), "en".into())]);
+    }
+
+    #[test]
+    fn test_check_tags() {
+        let result = parse(
+            "key,en,ko\n\
+             link,Read <a href=\"/terms\">terms</a> now,<a href=\"/ko/terms\">약관</a>을 읽으세요\n\
+             bold,<b>Hi</b> <1>there</1>,<b>안녕 <1>거기</b>\n\
+             dropped,<b>Save</b> all,모두 저장<br/>\n\
+             broken,Oops </i>,<i>앗</i>",
+        );
+
+        let issues: Vec<(ErrorKind, String, String)> = check_tags(&result, None, ".")
+            .iter()
+            .map(|i| {
+                let location = i.location.as_ref().unwrap();
+                (i.kind.clone(), location.key.clone().unwrap(), i.message.clone())
+            })
+            .collect();
+
+        assert_eq!(
+            issues,
+            vec![
+                (
+                    ErrorKind::TagUnbalanced,
+                    "broken".into(),
+                    "Key 'broken' (en): closing tag </i> has no opening tag".into()
+                ),
+                (
+                    ErrorKind::TagUnbalanced,
+                    "bold".into(),
+                    "Key 'bold' (ko): tag <1> is not closed before </b>".into()
+                ),
+                (
+                    ErrorKind::TagExtra,
+                    "broken".into(),
+                    "Key 'broken' (ko): tag <i> is not in the source text".into()
+                ),
+                (
+                    ErrorKind::TagMissing,
+                    "dropped".into(),
+                    "Key 'dropped' (ko): tag <b> from the source text is missing".into()
+                ),
+                (
+                    ErrorKind::TagExtra,
+                    "dropped".into(),
+                    "Key 'dropped' (ko): tag <br/> is not in the source text".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let result = parse(
+            "key,en,ko,ja\n\
+             a,A,가,\n\
+             b,B,,\n\
+             item_one,one,,\n\
+             item_other,many,개,つ\n\
+             ko_only,,한국어,",
+        );
+
+        let report = coverage_report(&result, None, ".");
+        assert_eq!(report.reference_language, "en");
+        assert_eq!(report.total_keys, 4);
+
+        let summary: Vec<(&str, usize, Vec<&str>)> = report
+            .languages
+            .iter()
+            .map(|l| (l.language.as_str(), l.translated, l.missing.iter().map(String::as_str).collect()))
+            .collect();
+        // item_one은 같은 복수형의 item_other가 있으므로 누락이 아님
+        assert_eq!(
+            summary,
+            vec![("en", 4, vec![]), ("ko", 3, vec!["b"]), ("ja", 2, vec!["a", "b"])]
+        );
+        assert_eq!(report.languages[1].percent, 75.0);
+
+        let errors = report.missing_errors();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].kind, ErrorKind::MissingTranslation);
+        let location = errors[0].location.as_ref().unwrap();
+        assert_eq!((location.column, location.column_name.as_deref()), (Some(3), Some("ko")));
+
+        let report = coverage_report(&result, Some("ko"), ".");
+        assert_eq!(report.languages[0].missing, vec!["ko_only"]);
+    }
+
+    #[test]
+    fn test_check_max_length() {
+        let result = parse(
+            "key,en,ko,ja,#maxLength\n\
+             button.save,Save changes,변경 사항 저장,変更を保存,10\n\
+             button.ok,OK,확인,OK,\n\
+             items,,,,5\n\
+             items_one,{{count}} item,{{count}}개,{{count}}件,\n\
+             title,👍🏽 Cafe\u{301},👍🏽 카페,,6",
+        );
+        let summary = |report: &LengthReport| -> Vec<String> {
+            report
+                .languages
+                .iter()
+                .flat_map(|l| {
+                    l.overflows
+                        .iter()
+                        .map(move |o| format!("{} {} {}/{}", l.language, o.key, o.length, o.max_length))
+                })
+                .collect()
+        };
+
+        // 복수형 키는 기본 키(items)의 제한을 따르고, 이모지 시퀀스와 결합 문자는 1글자
+        let report = check_max_length(&result, &BTreeMap::new(), LengthUnit::Graphemes, ".");
+        assert_eq!(report.limited_keys, 3);
+        assert_eq!(
+            summary(&report),
+            vec!["en button.save 12/10", "en items_one 14/5", "ko items_one 10/5", "ja items_one 10/5"]
+        );
+
+        // 표시 폭: 전각 문자와 이모지는 2칸
+        let report = check_max_length(&result, &BTreeMap::new(), LengthUnit::DisplayWidth, ".");
+        assert_eq!(
+            summary(&report),
+            vec![
+                "en button.save 12/10",
+                "en items_one 14/5",
+                "en title 7/6",
+                "ko button.save 14/10",
+                "ko items_one 11/5",
+                "ko title 7/6",
+                "ja items_one 11/5",
+            ]
+        );
+
+        // 외부 제한이 시트의 제한보다 우선
+        let limits = BTreeMap::from([("button.save".to_string(), 20), ("button.ok".to_string(), 1)]);
+        let report = check_max_length(&result, &limits, LengthUnit::Graphemes, ".");
+        assert_eq!(report.limited_keys, 4);
+        let errors = report.errors();
+        let keys: Vec<&str> = errors.iter().map(|e| e.location.as_ref().unwrap().key.as_deref().unwrap()).collect();
+        assert_eq!(keys, vec!["button.ok", "items_one", "button.ok", "items_one", "button.ok", "items_one"]);
+        assert_eq!(errors[2].kind, ErrorKind::MaxLengthExceeded);
+        assert_eq!(errors[2].location.as_ref().unwrap().column, Some(3));
+        assert!(!report.is_ok());
+    }
+}
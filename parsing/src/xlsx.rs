@@ -0,0 +1,239 @@
+//! xlsx 쓰기 (`xlsx` feature)
+//!
+//! `TableData`를 시트 하나짜리 워크북으로 만듭니다.
+//! - 헤더 행 고정 + 굵게
+//! - 내용에 맞춘 컬럼 너비
+//! - 비어 있는 번역 셀(누락된 번역)은 배경색으로 표시
+
+use crate::error::{ParseError, Result};
+use crate::export::TableData;
+use crate::transform::display_width;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// 시트 이름
+const SHEET_NAME: &str = "translations";
+
+/// 셀 스타일 인덱스 (`styles.xml`의 cellXfs 순서)
+const STYLE_HEADER: usize = 1;
+const STYLE_MISSING: usize = 2;
+
+/// 컬럼 너비 범위 (문자 수 기준)
+const MIN_WIDTH: usize = 8;
+const MAX_WIDTH: usize = 80;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+/// 0: 기본, 1: 헤더(굵게), 2: 누락(노란 배경)
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts><fills count="3"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill><fill><patternFill patternType="solid"><fgColor rgb="FFFFEB9C"/><bgColor indexed="64"/></patternFill></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="3"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/><xf numFmtId="0" fontId="0" fillId="2" borderId="0" xfId="0" applyFill="1"/></cellXfs><cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles></styleSheet>"#;
+
+/// `TableData` -> xlsx 파일 바이트
+///
+/// 첫 컬럼(key)을 제외한 빈 셀은 누락된 번역으로 보고 배경색을 칠합니다.
+pub fn table_to_xlsx(table: &TableData) -> Result<Vec<u8>> {
+    let workbook = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        SHEET_NAME
+    );
+
+    let parts = [
+        ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+        ("_rels/.rels", ROOT_RELS.to_string()),
+        ("xl/workbook.xml", workbook),
+        ("xl/_rels/workbook.xml.rels", WORKBOOK_RELS.to_string()),
+        ("xl/styles.xml", STYLES.to_string()),
+        ("xl/worksheets/sheet1.xml", sheet_xml(table)),
+    ];
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, content) in parts {
+        zip.start_file(name, options)
+            .map_err(|e| ParseError::xlsx_write_error(&e.to_string()))?;
+        zip.write_all(content.as_bytes())?;
+    }
+
+    let cursor = zip
+        .finish()
+        .map_err(|e| ParseError::xlsx_write_error(&e.to_string()))?;
+    Ok(cursor.into_inner())
+}
+
+fn sheet_xml(table: &TableData) -> String {
+    let columns = table
+        .rows
+        .iter()
+        .map(Vec::len)
+        .chain(std::iter::once(table.header.len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+    );
+
+    // 헤더 행 고정
+    xml.push_str(r#"<sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/><selection pane="bottomLeft"/></sheetView></sheetViews>"#);
+
+    if columns > 0 {
+        xml.push_str("<cols>");
+        for col in 0..columns {
+            let width = std::iter::once(&table.header)
+                .chain(&table.rows)
+                .filter_map(|row| row.get(col))
+                .map(|cell| text_width(cell))
+                .max()
+                .unwrap_or(0)
+                .clamp(MIN_WIDTH, MAX_WIDTH);
+            xml.push_str(&format!(
+                r#"<col min="{0}" max="{0}" width="{1}" customWidth="1"/>"#,
+                col + 1,
+                width + 2
+            ));
+        }
+        xml.push_str("</cols>");
+    }
+
+    xml.push_str("<sheetData>");
+    push_row(&mut xml, 0, &table.header, columns, |_, _| Some(STYLE_HEADER));
+    for (idx, row) in table.rows.iter().enumerate() {
+        push_row(&mut xml, idx + 1, row, columns, |col, value| {
+            (col > 0 && value.is_empty()).then_some(STYLE_MISSING)
+        });
+    }
+    xml.push_str("</sheetData></worksheet>");
+    xml
+}
+
+/// 행 하나 쓰기 (`row`는 0-based). 짧은 행은 빈 셀로 채움
+fn push_row(xml: &mut String, row: usize, cells: &[String], columns: usize, style: impl Fn(usize, &str) -> Option<usize>) {
+    xml.push_str(&format!(r#"<row r="{}">"#, row + 1));
+    for col in 0..columns {
+        let value = cells.get(col).map(String::as_str).unwrap_or("");
+        let reference = format!("{}{}", column_name(col), row + 1);
+        let style_attr = style(col, value)
+            .map(|s| format!(r#" s="{}""#, s))
+            .unwrap_or_default();
+
+        if value.is_empty() {
+            if !style_attr.is_empty() {
+                xml.push_str(&format!(r#"<c r="{}"{}/>"#, reference, style_attr));
+            }
+        } else {
+            xml.push_str(&format!(
+                r#"<c r="{}"{} t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                reference,
+                style_attr,
+                escape_xml(value)
+            ));
+        }
+    }
+    xml.push_str("</row>");
+}
+
+/// 0-based 컬럼 번호 -> 엑셀 컬럼 이름 (0 -> A, 26 -> AA)
+fn column_name(mut col: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (col % 26) as u8);
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// 셀 표시 너비 (여러 줄이면 가장 긴 줄, 전각 문자는 2칸)
+fn text_width(text: &str) -> usize {
+    text.lines().map(display_width).max().unwrap_or(0)
+}
+
+/// XML 특수 문자 이스케이프 (XML 1.0에서 허용되지 않는 제어 문자는 제거)
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::types::SheetSelection;
+    use std::io::Read;
+
+    fn sample_table() -> TableData {
+        TableData {
+            header: vec!["key".into(), "en".into(), "ko".into()],
+            rows: vec![
+                vec!["common.hello".into(), "Hello & <b>welcome</b>".into(), "안녕하세요".into()],
+                vec!["common.bye".into(), "Bye".into(), "".into()],
+            ],
+        }
+    }
+
+    fn read_part(xlsx: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(xlsx)).unwrap();
+        let mut content = String::new();
+        archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn test_table_to_xlsx_round_trip() {
+        let xlsx = table_to_xlsx(&sample_table()).unwrap();
+        let csv = parser::excel::sheet_to_csv(&xlsx, &SheetSelection::Index(0)).unwrap();
+        assert_eq!(
+            csv.trim_end(),
+            "key,en,ko\ncommon.hello,Hello & <b>welcome</b>,안녕하세요\ncommon.bye,Bye,"
+        );
+    }
+
+    #[test]
+    fn test_table_to_xlsx_styles() {
+        let xlsx = table_to_xlsx(&sample_table()).unwrap();
+        let sheet = read_part(&xlsx, "xl/worksheets/sheet1.xml");
+
+        assert!(sheet.contains(r#"state="frozen""#));
+        assert!(sheet.contains(r#"<c r="A1" s="1" t="inlineStr">"#));
+        // 누락된 번역만 표시
+        assert!(sheet.contains(r#"<c r="C3" s="2"/>"#));
+        assert!(!sheet.contains(r#"<c r="A3" s="2""#));
+        // "Hello & <b>welcome</b>" = 22 + 여백 2
+        assert!(sheet.contains(r#"<col min="2" max="2" width="24" customWidth="1"/>"#));
+        // "안녕하세요" = 전각 5자 -> 10 + 여백 2
+        assert!(sheet.contains(r#"<col min="3" max="3" width="12" customWidth="1"/>"#));
+    }
+
+    #[test]
+    fn test_column_name() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(701), "ZZ");
+        assert_eq!(column_name(702), "AAA");
+    }
+}